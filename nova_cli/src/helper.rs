@@ -1,10 +1,21 @@
 use nova_vm::ecmascript::{
     builtins::{create_builtin_function, ArgumentsList, Behaviour, BuiltinFunctionArgs},
     execution::{agent::ExceptionType, Agent, JsResult},
-    types::{InternalMethods, IntoValue, Object, PropertyDescriptor, PropertyKey, String, Value},
+    types::{
+        Function, InternalMethods, IntoValue, Object, PropertyDescriptor, PropertyKey, String,
+        Value,
+    },
 };
-use nova_vm::engine::context::GcScope;
+use nova_vm::engine::{context::GcScope, Global};
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use std::{
+    io::{BufRead, Read},
+    time::Duration,
+};
+
+use crate::CliHostHooks;
 
 /// Initialize the global object with the built-in functions.
 pub fn initialize_global_object(agent: &mut Agent, global: Object, mut gc: GcScope<'_, '_>) {
@@ -68,6 +79,82 @@ pub fn initialize_global_object(agent: &mut Agent, global: Object, mut gc: GcSco
         Ok(Value::Undefined)
     }
 
+    // `setTimeout` function
+    fn set_timeout(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        gc: GcScope<'_, '_>,
+    ) -> JsResult<Value> {
+        let Ok(callback) = Function::try_from(args.get(0)) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Expected a function argument",
+                gc.nogc(),
+            ));
+        };
+        // Converting the delay can call into JavaScript, so the callback must
+        // be rooted first.
+        let callback = Global::new(agent, callback.unbind());
+        let delay = match args.get(1).to_real(agent, gc) {
+            Ok(delay) => delay,
+            Err(err) => {
+                callback.take(agent);
+                return Err(err);
+            }
+        };
+        // NaN and negative delays mean "as soon as possible".
+        let delay = if delay > 0.0 {
+            Duration::from_secs_f64((delay / 1000.0).min(u32::MAX as f64))
+        } else {
+            Duration::ZERO
+        };
+        let host_hooks = agent
+            .get_host_data()
+            .downcast_ref::<CliHostHooks>()
+            .unwrap();
+        let id = host_hooks.enqueue_timeout(delay, callback);
+        Ok(Value::from(id))
+    }
+
+    // `read` function
+    fn read(agent: &mut Agent, _: Value, _: ArgumentsList, gc: GcScope<'_, '_>) -> JsResult<Value> {
+        let mut input = std::string::String::new();
+        let read = std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| agent.throw_exception(ExceptionType::Error, e.to_string(), gc.nogc()))?;
+        if read == 0 {
+            // EOF
+            return Ok(Value::Null);
+        }
+        Ok(String::from_string(agent, input, gc.nogc()).into_value())
+    }
+
+    // `readline` function
+    fn readline(
+        agent: &mut Agent,
+        _: Value,
+        _: ArgumentsList,
+        gc: GcScope<'_, '_>,
+    ) -> JsResult<Value> {
+        let mut line = std::string::String::new();
+        let read = std::io::stdin()
+            .lock()
+            .read_line(&mut line)
+            .map_err(|e| agent.throw_exception(ExceptionType::Error, e.to_string(), gc.nogc()))?;
+        if read == 0 {
+            // EOF
+            return Ok(Value::Null);
+        }
+        if line.ends_with('\n') {
+            line.pop();
+            if line.ends_with('\r') {
+                line.pop();
+            }
+        }
+        Ok(String::from_string(agent, line, gc.nogc()).into_value())
+    }
+
     let function = create_builtin_function(
         agent,
         Behaviour::Regular(print),
@@ -133,6 +220,102 @@ pub fn initialize_global_object(agent: &mut Agent, global: Object, mut gc: GcSco
             gc.reborrow(),
         )
         .unwrap();
+
+    let function = create_builtin_function(
+        agent,
+        Behaviour::Regular(set_timeout),
+        BuiltinFunctionArgs::new(2, "setTimeout", agent.current_realm_id()),
+        gc.nogc(),
+    );
+    let property_key = PropertyKey::from_static_str(agent, "setTimeout", gc.nogc()).unbind();
+    global
+        .internal_define_own_property(
+            agent,
+            property_key,
+            PropertyDescriptor {
+                value: Some(function.into_value()),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            },
+            gc.reborrow(),
+        )
+        .unwrap();
+
+    // Reading from standard input is a capability that must be requested.
+    let allow_stdin = agent
+        .get_host_data()
+        .downcast_ref::<CliHostHooks>()
+        .is_some_and(|host_hooks| host_hooks.allow_stdin);
+    if !allow_stdin {
+        return;
+    }
+
+    let function = create_builtin_function(
+        agent,
+        Behaviour::Regular(read),
+        BuiltinFunctionArgs::new(0, "read", agent.current_realm_id()),
+        gc.nogc(),
+    );
+    let property_key = PropertyKey::from_static_str(agent, "read", gc.nogc()).unbind();
+    global
+        .internal_define_own_property(
+            agent,
+            property_key,
+            PropertyDescriptor {
+                value: Some(function.into_value()),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            },
+            gc.reborrow(),
+        )
+        .unwrap();
+
+    let function = create_builtin_function(
+        agent,
+        Behaviour::Regular(readline),
+        BuiltinFunctionArgs::new(0, "readline", agent.current_realm_id()),
+        gc.nogc(),
+    );
+    let property_key = PropertyKey::from_static_str(agent, "readline", gc.nogc()).unbind();
+    global
+        .internal_define_own_property(
+            agent,
+            property_key,
+            PropertyDescriptor {
+                value: Some(function.into_value()),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            },
+            gc.reborrow(),
+        )
+        .unwrap();
+}
+
+/// Returns true if the source text fails to parse only because it ends too
+/// early, e.g. inside an unclosed block or argument list. The REPL uses this
+/// to ask for more lines instead of reporting the parse errors.
+pub fn is_incomplete_source(source: &str) -> bool {
+    let allocator = Default::default();
+    let source_type = SourceType::default().with_typescript(false);
+    let result = Parser::new(&allocator, source, source_type).parse();
+    if result.errors.is_empty() {
+        return false;
+    }
+    // Errors caused by the end of input point at or extend to the end of the
+    // source text.
+    result.errors.iter().all(|error| {
+        error.labels.as_ref().is_some_and(|labels| {
+            labels
+                .iter()
+                .any(|label| label.offset() + label.len() >= source.len())
+        })
+    })
 }
 
 /// Exit the program with parse errors.
@@ -160,3 +343,20 @@ pub fn exit_with_parse_errors(errors: Vec<OxcDiagnostic>, source_path: &str, sou
 
     std::process::exit(1);
 }
+
+#[cfg(test)]
+mod test {
+    use super::is_incomplete_source;
+
+    #[test]
+    fn incomplete_source() {
+        assert!(is_incomplete_source("function f() {"));
+        assert!(is_incomplete_source("function f() {\n  return 1"));
+        assert!(is_incomplete_source("foo(1,"));
+        assert!(is_incomplete_source("/* comment"));
+        assert!(!is_incomplete_source("function f() {\n return 1 }"));
+        assert!(!is_incomplete_source("1 +* 2"));
+        assert!(!is_incomplete_source("1 +* 2; {"));
+        assert!(!is_incomplete_source("let let = 1"));
+    }
+}
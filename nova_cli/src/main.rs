@@ -4,21 +4,34 @@
 mod helper;
 mod theme;
 
-use std::{cell::RefCell, collections::VecDeque, fmt::Debug};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, VecDeque},
+    fmt::Debug,
+    io::Read,
+    time::{Duration, Instant},
+};
 
 use clap::{Parser as ClapParser, Subcommand};
 use cliclack::{input, intro, set_theme};
-use helper::{exit_with_parse_errors, initialize_global_object};
+use helper::{exit_with_parse_errors, initialize_global_object, is_incomplete_source};
 use nova_vm::{
     ecmascript::{
         execution::{
             agent::{GcAgent, HostHooks, Job, Options},
             Agent,
         },
-        scripts_and_modules::script::{parse_script, script_evaluation},
-        types::{Object, String as JsString},
+        scripts_and_modules::script::{
+            parse_script, script_evaluation, script_evaluation_with_completion,
+        },
+        types::{
+            Function, InternalMethods, Object, PropertyDescriptor, PropertyKey, String as JsString,
+            Value,
+        },
     },
-    engine::context::GcScope,
+    engine::{context::GcScope, Global},
 };
 use oxc_parser::Parser;
 use oxc_semantic::{SemanticBuilder, SemanticBuilderReturn};
@@ -40,6 +53,10 @@ enum Command {
     Parse {
         /// The path of the file to parse
         path: String,
+
+        /// Print the AST as ESTree JSON, including node spans
+        #[arg(long)]
+        json: bool,
     },
 
     /// Evaluates a file
@@ -52,7 +69,16 @@ enum Command {
         #[arg(short, long)]
         no_strict: bool,
 
-        /// The files to evaluate
+        /// Expose the `read` and `readline` functions for reading from
+        /// standard input
+        #[arg(long)]
+        stdin: bool,
+
+        /// Print promise job and timer statistics to stderr after evaluation
+        #[arg(long)]
+        job_stats: bool,
+
+        /// The files to evaluate, `-` reads a script from standard input
         #[arg(required = true)]
         paths: Vec<String>,
     },
@@ -61,9 +87,48 @@ enum Command {
     Repl {},
 }
 
+/// A callback registered with `setTimeout`.
+struct Timer {
+    deadline: Instant,
+    /// Orders timers with the same deadline by registration.
+    id: u32,
+    callback: Global<Function<'static>>,
+}
+
+impl PartialEq for Timer {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Timer {}
+
+impl PartialOrd for Timer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.deadline, self.id).cmp(&(other.deadline, other.id))
+    }
+}
+
 #[derive(Default)]
 struct CliHostHooks {
     promise_job_queue: RefCell<VecDeque<Job>>,
+    /// Pending timers, earliest deadline first.
+    timers: RefCell<BinaryHeap<Reverse<Timer>>>,
+    next_timer_id: Cell<u32>,
+    /// Whether scripts are allowed to read from standard input.
+    allow_stdin: bool,
+    /// Number of promise jobs taken from the queue to be run.
+    promise_jobs_run: Cell<usize>,
+    /// Largest number of promise jobs that were queued at once.
+    peak_promise_queue_depth: Cell<usize>,
+    /// Number of timers taken from the timer heap to be run.
+    timers_run: Cell<usize>,
 }
 
 // RefCell doesn't implement Debug
@@ -71,19 +136,72 @@ impl Debug for CliHostHooks {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CliHostHooks")
             //.field("promise_job_queue", &*self.promise_job_queue.borrow())
+            .field("pending_timers", &self.timers.borrow().len())
+            .field("allow_stdin", &self.allow_stdin)
+            .field("promise_jobs_run", &self.promise_jobs_run)
+            .field("peak_promise_queue_depth", &self.peak_promise_queue_depth)
+            .field("timers_run", &self.timers_run)
             .finish()
     }
 }
 
 impl CliHostHooks {
     fn pop_promise_job(&self) -> Option<Job> {
-        self.promise_job_queue.borrow_mut().pop_front()
+        let job = self.promise_job_queue.borrow_mut().pop_front();
+        if job.is_some() {
+            self.promise_jobs_run.set(self.promise_jobs_run.get() + 1);
+        }
+        job
+    }
+
+    /// Schedules `callback` to be called once `delay` has passed and returns
+    /// the timer's id.
+    fn enqueue_timeout(&self, delay: Duration, callback: Global<Function<'static>>) -> u32 {
+        let id = self.next_timer_id.get() + 1;
+        self.next_timer_id.set(id);
+        self.timers.borrow_mut().push(Reverse(Timer {
+            deadline: Instant::now() + delay,
+            id,
+            callback,
+        }));
+        id
+    }
+
+    /// Takes the timer with the earliest deadline, sleeping until the
+    /// deadline has passed if necessary. This must only be called when there
+    /// is no other work to do.
+    fn pop_due_timer(&self) -> Option<Global<Function<'static>>> {
+        let Reverse(timer) = self.timers.borrow_mut().pop()?;
+        self.timers_run.set(self.timers_run.get() + 1);
+        let now = Instant::now();
+        if timer.deadline > now {
+            std::thread::sleep(timer.deadline - now);
+        }
+        Some(timer.callback)
+    }
+
+    fn print_job_stats(&self) {
+        eprintln!("Promise jobs run: {}", self.promise_jobs_run.get());
+        eprintln!(
+            "Peak promise job queue depth: {}",
+            self.peak_promise_queue_depth.get()
+        );
+        eprintln!("Timers run: {}", self.timers_run.get());
     }
 }
 
 impl HostHooks for CliHostHooks {
     fn enqueue_promise_job(&self, job: Job) {
-        self.promise_job_queue.borrow_mut().push_back(job);
+        let mut promise_job_queue = self.promise_job_queue.borrow_mut();
+        promise_job_queue.push_back(job);
+        let depth = promise_job_queue.len();
+        if depth > self.peak_promise_queue_depth.get() {
+            self.peak_promise_queue_depth.set(depth);
+        }
+    }
+
+    fn get_host_data(&self) -> &dyn Any {
+        self
     }
 }
 
@@ -91,7 +209,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Cli::parse();
 
     match args.command {
-        Command::Parse { path } => {
+        Command::Parse { path, json } => {
             let file = std::fs::read_to_string(&path)?;
             let allocator = Default::default();
             let source_type: SourceType = Default::default();
@@ -110,15 +228,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 exit_with_parse_errors(result.errors, &path, &file);
             }
 
-            println!("{:?}", result.program);
+            if json {
+                println!("{}", result.program.to_json());
+            } else {
+                println!("{:?}", result.program);
+            }
         }
         Command::Eval {
             verbose,
             no_strict,
             nogc,
+            stdin,
+            job_stats,
             paths,
         } => {
-            let host_hooks: &CliHostHooks = &*Box::leak(Box::default());
+            let host_hooks: &CliHostHooks = &*Box::leak(Box::new(CliHostHooks {
+                allow_stdin: stdin,
+                ..Default::default()
+            }));
             let mut agent = GcAgent::new(
                 Options {
                     disable_gc: nogc,
@@ -145,7 +272,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     &realm,
                     |agent, mut gc| -> Result<(), Box<dyn std::error::Error>> {
                         let realm = agent.current_realm_id();
-                        let file = std::fs::read_to_string(&path)?;
+                        let (file, display_name) = if path == "-" {
+                            let mut file = String::new();
+                            std::io::stdin().read_to_string(&mut file)?;
+                            (file, "<stdin>")
+                        } else {
+                            (std::fs::read_to_string(&path)?, path.as_str())
+                        };
                         let source_text = JsString::from_string(agent, file, gc.nogc());
                         let script = match parse_script(
                             agent,
@@ -159,14 +292,28 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             Err(errors) => {
                                 // Borrow the string data from the Agent
                                 let source_text = source_text.as_str(agent);
-                                exit_with_parse_errors(errors, &path, source_text)
+                                exit_with_parse_errors(errors, display_name, source_text)
                             }
                         };
                         let mut result = script_evaluation(agent, script, gc.reborrow());
 
                         if result.is_ok() {
-                            while let Some(job) = host_hooks.pop_promise_job() {
-                                if let Err(err) = job.run(agent, gc.reborrow()) {
+                            'event_loop: loop {
+                                while let Some(job) = host_hooks.pop_promise_job() {
+                                    if let Err(err) = job.run(agent, gc.reborrow()) {
+                                        result = Err(err);
+                                        break 'event_loop;
+                                    }
+                                }
+                                // Timers only run once all promise jobs have
+                                // settled.
+                                let Some(callback) = host_hooks.pop_due_timer() else {
+                                    break;
+                                };
+                                let callback = callback.take(agent);
+                                if let Err(err) =
+                                    callback.call(agent, Value::Undefined, &[], gc.reborrow())
+                                {
                                     result = Err(err);
                                     break;
                                 }
@@ -182,11 +329,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             Err(error) => {
                                 eprintln!(
                                     "Uncaught exception: {}",
-                                    error
-                                        .value()
-                                        .string_repr(agent, gc.reborrow())
-                                        .as_str(agent)
+                                    error.format(agent, gc.reborrow())
                                 );
+                                if job_stats {
+                                    host_hooks.print_job_stats();
+                                }
                                 std::process::exit(1);
                             }
                         }
@@ -195,6 +342,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 )?;
             }
             agent.remove_realm(realm);
+            if job_stats {
+                host_hooks.print_job_stats();
+            }
         }
         Command::Repl {} => {
             let host_hooks: &CliHostHooks = &*Box::leak(Box::default());
@@ -216,6 +366,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             set_theme(DefaultTheme);
             println!("\n\n");
             let mut placeholder = "Enter a line of Javascript".to_string();
+            let mut last_result: Option<Global<Value>> = None;
 
             // Register a signal handler for Ctrl+C
             let _ = ctrlc::set_handler(|| {
@@ -223,18 +374,47 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             });
             loop {
                 intro("Nova Repl (type exit or ctrl+c to exit)")?;
-                let input: String = input("").placeholder(&placeholder).interact()?;
+                let mut source: String = input("").placeholder(&placeholder).interact()?;
 
-                if input.matches("exit").count() == 1 {
+                if source.matches("exit").count() == 1 {
                     std::process::exit(0);
-                } else if input.matches("gc").count() == 1 {
+                } else if source.matches("gc").count() == 1 {
                     agent.gc();
                     continue;
                 }
-                placeholder = input.to_string();
+                // Keep reading lines while the input is incomplete, e.g. has
+                // an unclosed block. An empty line submits the input as is.
+                while is_incomplete_source(&source) {
+                    let line: String = input("...").required(false).interact()?;
+                    if line.is_empty() {
+                        break;
+                    }
+                    source.push('\n');
+                    source.push_str(&line);
+                }
+                placeholder = source.to_string();
                 agent.run_in_realm(&realm, |agent, mut gc| {
+                    if let Some(last_result) = &last_result {
+                        // Bind the previous result to `_`.
+                        let value = last_result.get(agent, gc.reborrow());
+                        let global = agent.current_realm().global_object();
+                        let property_key =
+                            PropertyKey::from_static_str(agent, "_", gc.nogc()).unbind();
+                        let _ = global.internal_define_own_property(
+                            agent,
+                            property_key,
+                            PropertyDescriptor {
+                                value: Some(value),
+                                writable: Some(true),
+                                enumerable: Some(false),
+                                configurable: Some(true),
+                                ..Default::default()
+                            },
+                            gc.reborrow(),
+                        );
+                    }
                     let realm = agent.current_realm_id();
-                    let source_text = JsString::from_string(agent, input, gc.nogc());
+                    let source_text = JsString::from_string(agent, source, gc.nogc());
                     let script =
                         match parse_script(agent, source_text, realm, true, None, gc.nogc()) {
                             Ok(script) => script,
@@ -242,19 +422,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 exit_with_parse_errors(errors, "<stdin>", &placeholder);
                             }
                         };
-                    let result = script_evaluation(agent, script, gc.reborrow());
+                    let result = script_evaluation_with_completion(agent, script, gc.reborrow());
                     match result {
-                        Ok(result) => {
-                            println!("{:?}\n", result);
+                        Ok(completion) => {
+                            // Declarations produce no value worth printing.
+                            if !completion.is_empty {
+                                println!("{:?}\n", completion.value);
+                                // Root the result so that it survives until
+                                // the next line binds it to `_`.
+                                let result = Global::new(agent, completion.value);
+                                if let Some(previous) = last_result.replace(result) {
+                                    previous.take(agent);
+                                }
+                            }
                         }
                         Err(error) => {
-                            eprintln!(
-                                "Uncaught exception: {}",
-                                error
-                                    .value()
-                                    .string_repr(agent, gc.reborrow())
-                                    .as_str(agent)
-                            );
+                            eprintln!("Uncaught exception: {}", error.format(agent, gc.reborrow()));
                         }
                     }
                 });
@@ -0,0 +1,91 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::process::Command;
+
+#[test]
+fn job_stats_count_promise_jobs() {
+    let path = std::env::temp_dir().join(format!("nova_cli_job_stats_{}.js", std::process::id()));
+    std::fs::write(
+        &path,
+        r#"
+        Promise.resolve().then(() => {}).then(() => {}).then(() => print("chain"));
+        Promise.resolve().then(() => {});
+        Promise.resolve().then(() => {});
+        "#,
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_nova_cli"))
+        .args(["eval", "--job-stats"])
+        .arg(&path)
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    // The statistics must not pollute the program's own output.
+    assert_eq!(std::str::from_utf8(&output.stdout).unwrap(), "chain\n");
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "Promise jobs run: 5\nPeak promise job queue depth: 3\nTimers run: 0\n"
+    );
+}
+
+#[test]
+fn job_stats_printed_after_uncaught_exception() {
+    let path = std::env::temp_dir().join(format!(
+        "nova_cli_job_stats_uncaught_{}.js",
+        std::process::id()
+    ));
+    std::fs::write(
+        &path,
+        r#"
+        Promise.resolve().then(() => {});
+        Promise.resolve().then(() => {});
+        setTimeout(() => { throw new Error("boom"); }, 0);
+        "#,
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_nova_cli"))
+        .args(["eval", "--job-stats"])
+        .arg(&path)
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(!output.status.success(), "{:?}", output);
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "Uncaught exception: Error: boom\nPromise jobs run: 2\nPeak promise job queue depth: 2\nTimers run: 1\n"
+    );
+}
+
+#[test]
+fn job_stats_count_timers() {
+    let path = std::env::temp_dir().join(format!(
+        "nova_cli_job_stats_timers_{}.js",
+        std::process::id()
+    ));
+    std::fs::write(
+        &path,
+        r#"
+        setTimeout(() => {
+            Promise.resolve().then(() => {});
+            setTimeout(() => print("done"), 0);
+        }, 0);
+        setTimeout(() => {}, 0);
+        "#,
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_nova_cli"))
+        .args(["eval", "--job-stats"])
+        .arg(&path)
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(std::str::from_utf8(&output.stdout).unwrap(), "done\n");
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "Promise jobs run: 1\nPeak promise job queue depth: 1\nTimers run: 3\n"
+    );
+}
@@ -0,0 +1,30 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::process::Command;
+
+#[test]
+fn parse_json_outputs_estree_nodes() {
+    let path = std::env::temp_dir().join(format!("nova_cli_parse_json_{}.js", std::process::id()));
+    std::fs::write(&path, "function add(a, b) { return a + b; }\nadd(1, 2);\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_nova_cli"))
+        .args(["parse", "--json"])
+        .arg(&path)
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let json = std::str::from_utf8(&output.stdout).unwrap();
+    for node_kind in [
+        r#""type":"Program""#,
+        r#""type":"FunctionDeclaration""#,
+        r#""type":"ReturnStatement""#,
+        r#""type":"BinaryExpression""#,
+        r#""type":"CallExpression""#,
+    ] {
+        assert!(json.contains(node_kind), "{node_kind} missing from {json}");
+    }
+    // Spans map the nodes back to the source text.
+    assert!(json.starts_with(r#"{"type":"Program","start":0,"end":48"#));
+}
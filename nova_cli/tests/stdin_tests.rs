@@ -0,0 +1,127 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+fn run_with_stdin(
+    name: &str,
+    script: &str,
+    input: &str,
+    allow_stdin: bool,
+) -> std::process::Output {
+    let path = std::env::temp_dir().join(format!("nova_cli_{}_{}.js", name, std::process::id()));
+    std::fs::write(&path, script).unwrap();
+    let mut command = Command::new(env!("CARGO_BIN_EXE_nova_cli"));
+    command.arg("eval");
+    if allow_stdin {
+        command.arg("--stdin");
+    }
+    let mut child = command
+        .arg(&path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    output
+}
+
+#[test]
+fn readline_reads_lines_in_order() {
+    let output = run_with_stdin(
+        "readline",
+        r#"
+        let line;
+        while ((line = readline())) {
+            print("got " + line);
+        }
+        print("eof " + line);
+        "#,
+        "first\nsecond\r\nthird",
+        true,
+    );
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        std::str::from_utf8(&output.stdout).unwrap(),
+        "got first\ngot second\ngot third\neof null\n"
+    );
+}
+
+#[test]
+fn read_reads_all_input() {
+    let output = run_with_stdin(
+        "read",
+        r#"
+        print(readline());
+        print(read());
+        print(read());
+        "#,
+        "first\nsecond\nthird\n",
+        true,
+    );
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        std::str::from_utf8(&output.stdout).unwrap(),
+        "first\nsecond\nthird\n\nnull\n"
+    );
+}
+
+#[test]
+fn stdin_functions_require_flag() {
+    let output = run_with_stdin(
+        "no_flag",
+        r#"print(typeof read + " " + typeof readline);"#,
+        "",
+        false,
+    );
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        std::str::from_utf8(&output.stdout).unwrap(),
+        "undefined undefined\n"
+    );
+}
+
+fn eval_piped_script(script: &str) -> std::process::Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_nova_cli"))
+        .args(["eval", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(script.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn eval_reads_script_from_stdin() {
+    let output = eval_piped_script("print(\"piped \" + (1 + 2));\n");
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(std::str::from_utf8(&output.stdout).unwrap(), "piped 3\n");
+}
+
+#[test]
+fn eval_stdin_parse_errors_name_stdin() {
+    let output = eval_piped_script("let = ;\n");
+    assert!(!output.status.success(), "{:?}", output);
+    assert!(std::str::from_utf8(&output.stderr)
+        .unwrap()
+        .contains("<stdin>:1:"));
+}
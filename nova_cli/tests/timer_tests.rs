@@ -0,0 +1,55 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::process::Command;
+
+#[test]
+fn set_timeout_runs_before_exit() {
+    let path = std::env::temp_dir().join(format!("nova_cli_timer_{}.js", std::process::id()));
+    std::fs::write(
+        &path,
+        r#"
+        setTimeout(() => print("later"), 20);
+        setTimeout(() => {
+            print("timeout");
+            Promise.resolve().then(() => print("job"));
+        }, 10);
+        Promise.resolve().then(() => print("promise"));
+        print("sync");
+        "#,
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_nova_cli"))
+        .arg("eval")
+        .arg(&path)
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(
+        std::str::from_utf8(&output.stdout).unwrap(),
+        "sync\npromise\ntimeout\njob\nlater\n"
+    );
+}
+
+#[test]
+fn set_timeout_callback_errors_are_uncaught() {
+    let path = std::env::temp_dir().join(format!("nova_cli_timer_error_{}.js", std::process::id()));
+    std::fs::write(
+        &path,
+        r#"setTimeout(() => { throw new Error("boom"); }, 10);"#,
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_nova_cli"))
+        .arg("eval")
+        .arg(&path)
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(!output.status.success(), "{:?}", output);
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "Uncaught exception: Error: boom\n"
+    );
+}
@@ -680,7 +680,7 @@ impl InternalMethods for Array<'_> {
             Default::default()
         };
         let elements = agent[self].elements;
-        let mut keys = Vec::with_capacity(elements.len() as usize + backing_keys.len());
+        let mut keys = Vec::with_capacity(elements.len() as usize + 1 + backing_keys.len());
 
         let elements_data = &agent[elements];
 
@@ -690,6 +690,9 @@ impl InternalMethods for Array<'_> {
             }
         }
 
+        // "length" is the first non-index property created on every Array.
+        keys.push(BUILTIN_STRING_MEMORY.length.into());
+
         keys.extend(backing_keys);
 
         TryResult::Continue(keys)
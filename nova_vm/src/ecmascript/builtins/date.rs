@@ -2,9 +2,13 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+pub(crate) mod abstract_operations;
 pub(crate) mod data;
 
-use std::ops::{Index, IndexMut};
+use std::{
+    ops::{Index, IndexMut},
+    time::{Duration, SystemTime},
+};
 
 use crate::{
     ecmascript::{
@@ -13,6 +17,11 @@ use crate::{
             InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
         },
     },
+    engine::{
+        context::NoGcScope,
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+        Scoped,
+    },
     heap::{
         indexes::DateIndex, CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues,
     },
@@ -32,6 +41,39 @@ impl Date {
     pub(crate) const fn get_index(self) -> usize {
         self.0.into_index()
     }
+
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, Date> {
+        Scoped::new(agent, self, gc)
+    }
+
+    /// Returns the \[\[DateValue\]\] of the Date as a time value in
+    /// milliseconds since the epoch, or NaN if the Date is invalid.
+    pub(crate) fn date_value(self, agent: &Agent) -> f64 {
+        let Some(date) = agent[self].date else {
+            return f64::NAN;
+        };
+        match date.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => duration.as_millis() as f64,
+            Err(err) => -(err.duration().as_millis() as f64),
+        }
+    }
+
+    /// Sets the \[\[DateValue\]\] of the Date from a time value. The time
+    /// value must already be clipped with TimeClip; NaN makes the Date
+    /// invalid.
+    pub(crate) fn set_date_value(self, agent: &mut Agent, time_value: f64) {
+        agent[self].date = if time_value.is_nan() {
+            None
+        } else if time_value >= 0.0 {
+            SystemTime::UNIX_EPOCH.checked_add(Duration::from_millis(time_value as u64))
+        } else {
+            SystemTime::UNIX_EPOCH.checked_sub(Duration::from_millis(-time_value as u64))
+        };
+    }
 }
 
 impl IntoValue for Date {
@@ -132,6 +174,34 @@ impl IndexMut<Date> for Vec<Option<DateHeapData>> {
     }
 }
 
+impl Rootable for Date {
+    type RootRepr = HeapRootRef;
+
+    #[inline]
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::Date(value))
+    }
+
+    #[inline]
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    #[inline]
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    #[inline]
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        if let HeapRootData::Date(value) = heap_data {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
 impl HeapMarkAndSweep for Date {
     fn mark_values(&self, queues: &mut WorkQueues) {
         queues.dates.push(*self);
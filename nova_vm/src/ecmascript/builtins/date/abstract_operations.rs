@@ -0,0 +1,518 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [21.4.1 Overview of Date Objects and Definitions of Abstract Operations](https://tc39.es/ecma262/#sec-overview-of-date-objects-and-definitions-of-abstract-operations)
+//!
+//! Time values are represented as f64 milliseconds since the epoch, with NaN
+//! standing for an invalid time value.
+//!
+//! Nova does not yet know the host's time zone: local time is UTC.
+
+/// ### [21.4.1.2 Time-related Constants](https://tc39.es/ecma262/#sec-time-related-constants)
+pub(crate) const MS_PER_SECOND: f64 = 1000.0;
+pub(crate) const MS_PER_MINUTE: f64 = 60000.0;
+pub(crate) const MS_PER_HOUR: f64 = 3600000.0;
+pub(crate) const MS_PER_DAY: f64 = 86400000.0;
+
+/// Largest absolute time value that TimeClip accepts.
+const MAX_TIME_VALUE: f64 = 8.64e15;
+
+const WEEK_DAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Number of days before the first day of each month in a common year.
+const DAYS_BEFORE_MONTH: [f64; 12] = [
+    0.0, 31.0, 59.0, 90.0, 120.0, 151.0, 181.0, 212.0, 243.0, 273.0, 304.0, 334.0,
+];
+
+/// ToIntegerOrInfinity for a finite or infinite Number.
+fn to_integer_or_infinity(value: f64) -> f64 {
+    // Adding +0 turns -0 into +0.
+    value.trunc() + 0.0
+}
+
+/// 𝔽(ℝ(x) modulo ℝ(y)): the result has the sign of y and is never -0.
+fn modulo(x: f64, y: f64) -> f64 {
+    x.rem_euclid(y) + 0.0
+}
+
+/// ### [21.4.1.3 Day ( t )](https://tc39.es/ecma262/#sec-day)
+pub(crate) fn day(t: f64) -> f64 {
+    (t / MS_PER_DAY).floor()
+}
+
+/// ### [21.4.1.4 TimeWithinDay ( t )](https://tc39.es/ecma262/#sec-timewithinday)
+pub(crate) fn time_within_day(t: f64) -> f64 {
+    modulo(t, MS_PER_DAY)
+}
+
+/// ### [21.4.1.5 DaysInYear ( y )](https://tc39.es/ecma262/#sec-daysinyear)
+fn days_in_year(y: f64) -> f64 {
+    if y % 4.0 != 0.0 || (y % 100.0 == 0.0 && y % 400.0 != 0.0) {
+        365.0
+    } else {
+        366.0
+    }
+}
+
+/// ### [21.4.1.6 DayFromYear ( y )](https://tc39.es/ecma262/#sec-dayfromyear)
+fn day_from_year(y: f64) -> f64 {
+    365.0 * (y - 1970.0) + ((y - 1969.0) / 4.0).floor() - ((y - 1901.0) / 100.0).floor()
+        + ((y - 1601.0) / 400.0).floor()
+}
+
+/// ### [21.4.1.7 TimeFromYear ( y )](https://tc39.es/ecma262/#sec-timefromyear)
+fn time_from_year(y: f64) -> f64 {
+    MS_PER_DAY * day_from_year(y)
+}
+
+/// ### [21.4.1.8 YearFromTime ( t )](https://tc39.es/ecma262/#sec-yearfromtime)
+pub(crate) fn year_from_time(t: f64) -> f64 {
+    // Start from an estimate using the average length of a year and correct
+    // it to the largest year that starts at or before t.
+    let mut y = (t / (MS_PER_DAY * 365.2425)).floor() + 1970.0;
+    while time_from_year(y) > t {
+        y -= 1.0;
+    }
+    while time_from_year(y + 1.0) <= t {
+        y += 1.0;
+    }
+    y
+}
+
+/// ### [21.4.1.9 DayWithinYear ( t )](https://tc39.es/ecma262/#sec-daywithinyear)
+fn day_within_year(t: f64) -> f64 {
+    day(t) - day_from_year(year_from_time(t))
+}
+
+/// ### [21.4.1.10 InLeapYear ( t )](https://tc39.es/ecma262/#sec-inleapyear)
+fn in_leap_year(t: f64) -> bool {
+    days_in_year(year_from_time(t)) == 366.0
+}
+
+/// Number of days before the first day of the month in the given year.
+fn days_before_month(month: usize, leap_year: bool) -> f64 {
+    if leap_year && month >= 2 {
+        DAYS_BEFORE_MONTH[month] + 1.0
+    } else {
+        DAYS_BEFORE_MONTH[month]
+    }
+}
+
+/// ### [21.4.1.11 MonthFromTime ( t )](https://tc39.es/ecma262/#sec-monthfromtime)
+pub(crate) fn month_from_time(t: f64) -> f64 {
+    let day_within_year = day_within_year(t);
+    let leap_year = in_leap_year(t);
+    let month = (1..12)
+        .take_while(|&month| days_before_month(month, leap_year) <= day_within_year)
+        .last()
+        .unwrap_or(0);
+    month as f64
+}
+
+/// ### [21.4.1.12 DateFromTime ( t )](https://tc39.es/ecma262/#sec-datefromtime)
+pub(crate) fn date_from_time(t: f64) -> f64 {
+    let month = month_from_time(t) as usize;
+    day_within_year(t) - days_before_month(month, in_leap_year(t)) + 1.0
+}
+
+/// ### [21.4.1.13 WeekDay ( t )](https://tc39.es/ecma262/#sec-weekday)
+pub(crate) fn week_day(t: f64) -> f64 {
+    modulo(day(t) + 4.0, 7.0)
+}
+
+/// ### [21.4.1.14 HourFromTime ( t )](https://tc39.es/ecma262/#sec-hourfromtime)
+pub(crate) fn hour_from_time(t: f64) -> f64 {
+    modulo((t / MS_PER_HOUR).floor(), 24.0)
+}
+
+/// ### [21.4.1.15 MinFromTime ( t )](https://tc39.es/ecma262/#sec-minfromtime)
+pub(crate) fn min_from_time(t: f64) -> f64 {
+    modulo((t / MS_PER_MINUTE).floor(), 60.0)
+}
+
+/// ### [21.4.1.16 SecFromTime ( t )](https://tc39.es/ecma262/#sec-secfromtime)
+pub(crate) fn sec_from_time(t: f64) -> f64 {
+    modulo((t / MS_PER_SECOND).floor(), 60.0)
+}
+
+/// ### [21.4.1.17 msFromTime ( t )](https://tc39.es/ecma262/#sec-msfromtime)
+pub(crate) fn ms_from_time(t: f64) -> f64 {
+    modulo(t, MS_PER_SECOND)
+}
+
+/// ### [21.4.1.25 LocalTime ( t )](https://tc39.es/ecma262/#sec-localtime)
+pub(crate) fn local_time(t: f64) -> f64 {
+    // The local time zone is UTC, so the offset is always zero.
+    t
+}
+
+/// ### [21.4.1.26 UTC ( t )](https://tc39.es/ecma262/#sec-utc-t)
+pub(crate) fn utc(t: f64) -> f64 {
+    if !t.is_finite() {
+        return f64::NAN;
+    }
+    // The local time zone is UTC, so the offset is always zero.
+    t
+}
+
+/// ### [21.4.1.27 MakeTime ( hour, min, sec, ms )](https://tc39.es/ecma262/#sec-maketime)
+pub(crate) fn make_time(hour: f64, min: f64, sec: f64, ms: f64) -> f64 {
+    // 1. If hour is not finite, min is not finite, sec is not finite, or ms
+    //    is not finite, return NaN.
+    if !hour.is_finite() || !min.is_finite() || !sec.is_finite() || !ms.is_finite() {
+        return f64::NAN;
+    }
+    // 2. Let h be 𝔽(! ToIntegerOrInfinity(hour)).
+    let h = to_integer_or_infinity(hour);
+    // 3. Let m be 𝔽(! ToIntegerOrInfinity(min)).
+    let m = to_integer_or_infinity(min);
+    // 4. Let s be 𝔽(! ToIntegerOrInfinity(sec)).
+    let s = to_integer_or_infinity(sec);
+    // 5. Let milli be 𝔽(! ToIntegerOrInfinity(ms)).
+    let milli = to_integer_or_infinity(ms);
+    // 6. Return ((h × msPerHour + m × msPerMinute) + s × msPerSecond) + milli.
+    ((h * MS_PER_HOUR + m * MS_PER_MINUTE) + s * MS_PER_SECOND) + milli
+}
+
+/// ### [21.4.1.28 MakeDay ( year, month, date )](https://tc39.es/ecma262/#sec-makeday)
+pub(crate) fn make_day(year: f64, month: f64, date: f64) -> f64 {
+    // 1. If year is not finite or month is not finite or date is not finite,
+    //    return NaN.
+    if !year.is_finite() || !month.is_finite() || !date.is_finite() {
+        return f64::NAN;
+    }
+    // 2. Let y be 𝔽(! ToIntegerOrInfinity(year)).
+    let y = to_integer_or_infinity(year);
+    // 3. Let m be 𝔽(! ToIntegerOrInfinity(month)).
+    let m = to_integer_or_infinity(month);
+    // 4. Let dt be 𝔽(! ToIntegerOrInfinity(date)).
+    let dt = to_integer_or_infinity(date);
+    // 5. Let ym be y + 𝔽(floor(ℝ(m) / 12)).
+    let ym = y + (m / 12.0).floor();
+    // 6. If ym is not finite, return NaN.
+    // Note: Years this far out are not representable as time values, so
+    // treat them as out of range before the day arithmetic loses precision.
+    if !ym.is_finite() || ym.abs() > 400000.0 {
+        return f64::NAN;
+    }
+    // 7. Let mn be 𝔽(ℝ(m) modulo 12).
+    let mn = modulo(m, 12.0);
+    // 8. Find a finite time value t such that YearFromTime(t) is ym,
+    //    MonthFromTime(t) is mn, and DateFromTime(t) is 1𝔽; but if this is
+    //    not possible (because some argument is out of range), return NaN.
+    let leap_year = days_in_year(ym) == 366.0;
+    let days = day_from_year(ym) + days_before_month(mn as usize, leap_year);
+    // 9. Return Day(t) + dt - 1𝔽.
+    days + dt - 1.0
+}
+
+/// ### [21.4.1.29 MakeDate ( day, time )](https://tc39.es/ecma262/#sec-makedate)
+pub(crate) fn make_date(day: f64, time: f64) -> f64 {
+    // 1. If day is not finite or time is not finite, return NaN.
+    if !day.is_finite() || !time.is_finite() {
+        return f64::NAN;
+    }
+    // 2. Let tv be day × msPerDay + time.
+    let tv = day * MS_PER_DAY + time;
+    // 3. If tv is not finite, return NaN.
+    if !tv.is_finite() {
+        return f64::NAN;
+    }
+    // 4. Return tv.
+    tv
+}
+
+/// ### [21.4.1.30 MakeFullYear ( year )](https://tc39.es/ecma262/#sec-makefullyear)
+pub(crate) fn make_full_year(year: f64) -> f64 {
+    // 1. If year is NaN, return NaN.
+    if year.is_nan() {
+        return f64::NAN;
+    }
+    // 2. Let truncated be ! ToIntegerOrInfinity(year).
+    let truncated = to_integer_or_infinity(year);
+    // 3. If truncated is in the inclusive interval from 0 to 99, return
+    //    1900𝔽 + 𝔽(truncated).
+    if (0.0..=99.0).contains(&truncated) {
+        return 1900.0 + truncated;
+    }
+    // 4. Return 𝔽(truncated).
+    truncated
+}
+
+/// ### [21.4.1.31 TimeClip ( time )](https://tc39.es/ecma262/#sec-timeclip)
+pub(crate) fn time_clip(time: f64) -> f64 {
+    // 1. If time is not finite, return NaN.
+    // 2. If abs(ℝ(time)) > 8.64 × 10**15, return NaN.
+    if !time.is_finite() || time.abs() > MAX_TIME_VALUE {
+        return f64::NAN;
+    }
+    // 3. Return 𝔽(! ToIntegerOrInfinity(time)).
+    to_integer_or_infinity(time)
+}
+
+/// Formats the year of a time value with at least four digits, as used by
+/// DateString and ToUTCString.
+fn format_year(tv: f64) -> String {
+    let year = year_from_time(tv);
+    let sign = if year >= 0.0 { "" } else { "-" };
+    format!("{}{:04}", sign, year.abs())
+}
+
+/// ### [21.4.4.41.1 TimeString ( tv )](https://tc39.es/ecma262/#sec-timestring)
+pub(crate) fn time_string(tv: f64) -> String {
+    format!(
+        "{:02}:{:02}:{:02} GMT",
+        hour_from_time(tv),
+        min_from_time(tv),
+        sec_from_time(tv)
+    )
+}
+
+/// ### [21.4.4.41.2 DateString ( tv )](https://tc39.es/ecma262/#sec-datestring)
+pub(crate) fn date_string(tv: f64) -> String {
+    format!(
+        "{} {} {:02} {}",
+        WEEK_DAY_NAMES[week_day(tv) as usize],
+        MONTH_NAMES[month_from_time(tv) as usize],
+        date_from_time(tv),
+        format_year(tv)
+    )
+}
+
+/// ### [21.4.4.41.3 TimeZoneString ( tv )](https://tc39.es/ecma262/#sec-timezoneestring)
+pub(crate) fn time_zone_string(_tv: f64) -> String {
+    // The local time zone is UTC.
+    "+0000 (Coordinated Universal Time)".to_string()
+}
+
+/// ### [21.4.4.41.4 ToDateString ( tv )](https://tc39.es/ecma262/#sec-todatestring)
+///
+/// Returns None for an invalid time value, which is formatted as
+/// "Invalid Date".
+pub(crate) fn to_date_string(tv: f64) -> Option<String> {
+    // 1. If tv is NaN, return "Invalid Date".
+    if tv.is_nan() {
+        return None;
+    }
+    // 2. Let t be LocalTime(tv).
+    let t = local_time(tv);
+    // 3. Return the string-concatenation of DateString(t), the code unit
+    //    0x0020 (SPACE), TimeString(t), and TimeZoneString(tv).
+    Some(format!(
+        "{} {}{}",
+        date_string(t),
+        time_string(t),
+        time_zone_string(tv)
+    ))
+}
+
+/// ### [21.4.4.43 Date.prototype.toUTCString ( )](https://tc39.es/ecma262/#sec-date.prototype.toutcstring)
+///
+/// Steps 5 to 11 of toUTCString for a valid time value.
+pub(crate) fn utc_string(tv: f64) -> String {
+    format!(
+        "{}, {:02} {} {} {}",
+        WEEK_DAY_NAMES[week_day(tv) as usize],
+        date_from_time(tv),
+        MONTH_NAMES[month_from_time(tv) as usize],
+        format_year(tv),
+        time_string(tv)
+    )
+}
+
+/// ### [21.4.1.32 Date Time String Format](https://tc39.es/ecma262/#sec-date-time-string-format)
+///
+/// Formats a valid time value in the simplified ISO 8601 format, using the
+/// expanded six digit year format for years outside 0 to 9999.
+pub(crate) fn iso_string(tv: f64) -> String {
+    let year = year_from_time(tv);
+    let year = if (0.0..=9999.0).contains(&year) {
+        format!("{:04}", year)
+    } else if year < 0.0 {
+        format!("-{:06}", -year)
+    } else {
+        format!("+{:06}", year)
+    };
+    format!(
+        "{}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month_from_time(tv) + 1.0,
+        date_from_time(tv),
+        hour_from_time(tv),
+        min_from_time(tv),
+        sec_from_time(tv),
+        ms_from_time(tv)
+    )
+}
+
+/// ### [21.4.3.2 Date.parse ( string )](https://tc39.es/ecma262/#sec-date.parse)
+///
+/// Parses a string in the Date Time String Format, returning its time value.
+/// Date-only forms are interpreted as UTC and date-time forms without a time
+/// zone offset as local time. Strings that are not in the format, including
+/// ones with out of range fields, are not recognised and return NaN.
+pub(crate) fn parse_date(s: &str) -> f64 {
+    let mut parser = DateTimeParser {
+        bytes: s.as_bytes(),
+        position: 0,
+    };
+    parser.parse().map_or(f64::NAN, time_clip)
+}
+
+/// Parser for the [Date Time String Format](https://tc39.es/ecma262/#sec-date-time-string-format),
+/// `YYYY-MM-DDTHH:mm:ss.sssZ`, including the expanded `±YYYYYY` years.
+struct DateTimeParser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl DateTimeParser<'_> {
+    fn parse(&mut self) -> Option<f64> {
+        // The year is either four digits or a sign followed by six digits.
+        let year = match self.peek() {
+            Some(sign @ (b'+' | b'-')) => {
+                self.position += 1;
+                let year = self.digits(6)?;
+                // -000000 is not a valid year.
+                if sign == b'-' {
+                    if year == 0.0 {
+                        return None;
+                    }
+                    -year
+                } else {
+                    year
+                }
+            }
+            _ => self.digits(4)?,
+        };
+        let mut month = 1.0;
+        let mut date = 1.0;
+        if self.eat(b'-') {
+            month = self.digits(2)?;
+            if self.eat(b'-') {
+                date = self.digits(2)?;
+            }
+        }
+        if !(1.0..=12.0).contains(&month) {
+            return None;
+        }
+        let month = month - 1.0;
+        let leap_year = days_in_year(year) == 366.0;
+        let days_in_month = if month == 11.0 {
+            31.0
+        } else {
+            days_before_month(month as usize + 1, leap_year)
+                - days_before_month(month as usize, leap_year)
+        };
+        if !(1.0..=days_in_month).contains(&date) {
+            return None;
+        }
+
+        let (mut hour, mut min, mut sec, mut ms) = (0.0, 0.0, 0.0, 0.0);
+        // Date-only forms are UTC, date-time forms default to local time.
+        let mut offset = Some(0.0);
+        if self.eat(b'T') {
+            hour = self.digits(2)?;
+            if !self.eat(b':') {
+                return None;
+            }
+            min = self.digits(2)?;
+            if self.eat(b':') {
+                sec = self.digits(2)?;
+                if self.eat(b'.') {
+                    ms = self.milliseconds()?;
+                }
+            }
+            // 24:00 is the end of the day and only valid with zero minutes,
+            // seconds and milliseconds.
+            let end_of_day = hour == 24.0 && min == 0.0 && sec == 0.0 && ms == 0.0;
+            if (hour > 23.0 && !end_of_day) || min > 59.0 || sec > 59.0 {
+                return None;
+            }
+            offset = self.offset()?;
+        }
+        if self.position != self.bytes.len() {
+            return None;
+        }
+
+        let tv = make_date(make_day(year, month, date), make_time(hour, min, sec, ms));
+        Some(match offset {
+            Some(offset) => tv - offset,
+            None => utc(tv),
+        })
+    }
+
+    /// Parses the time zone offset of a date-time form: `Z` or `±HH:mm`.
+    /// Returns `Some(None)` if there is no offset, meaning local time.
+    fn offset(&mut self) -> Option<Option<f64>> {
+        match self.peek() {
+            Some(b'Z') => {
+                self.position += 1;
+                Some(Some(0.0))
+            }
+            Some(sign @ (b'+' | b'-')) => {
+                self.position += 1;
+                let hours = self.digits(2)?;
+                if !self.eat(b':') {
+                    return None;
+                }
+                let minutes = self.digits(2)?;
+                if hours > 23.0 || minutes > 59.0 {
+                    return None;
+                }
+                let offset = hours * MS_PER_HOUR + minutes * MS_PER_MINUTE;
+                Some(Some(if sign == b'-' { -offset } else { offset }))
+            }
+            _ => Some(None),
+        }
+    }
+
+    /// Parses the fraction of a second, of which only the first three digits
+    /// are significant.
+    fn milliseconds(&mut self) -> Option<f64> {
+        let start = self.position;
+        let mut ms = 0.0;
+        while let Some(digit) = self.peek().filter(u8::is_ascii_digit) {
+            if self.position - start < 3 {
+                ms = ms * 10.0 + f64::from(digit - b'0');
+            }
+            self.position += 1;
+        }
+        match self.position - start {
+            0 => None,
+            1 => Some(ms * 100.0),
+            2 => Some(ms * 10.0),
+            _ => Some(ms),
+        }
+    }
+
+    /// Parses exactly `count` decimal digits.
+    fn digits(&mut self, count: usize) -> Option<f64> {
+        let digits = self.bytes.get(self.position..self.position + count)?;
+        let mut value = 0.0;
+        for &digit in digits {
+            if !digit.is_ascii_digit() {
+                return None;
+            }
+            value = value * 10.0 + f64::from(digit - b'0');
+        }
+        self.position += count;
+        Some(value)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let matches = self.peek() == Some(byte);
+        if matches {
+            self.position += 1;
+        }
+        matches
+    }
+}
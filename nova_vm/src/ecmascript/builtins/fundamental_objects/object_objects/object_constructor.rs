@@ -734,8 +734,7 @@ impl ObjectConstructor {
         let groups = group_by_property(agent, items, callback_fn, gc.reborrow())?;
 
         // 2. Let obj be OrdinaryObjectCreate(null).
-        let object =
-            ordinary_object_create_with_intrinsics(agent, Some(ProtoIntrinsics::Object), None);
+        let object = ordinary_object_create_with_intrinsics(agent, None, None);
 
         // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
         for g in groups {
@@ -3,6 +3,8 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use ahash::AHashSet;
+use num_bigint::BigUint;
+use num_traits::{Num, ToPrimitive};
 use oxc_ast::ast::{BindingIdentifier, Program, VariableDeclarationKind};
 use oxc_ecmascript::BoundNames;
 use oxc_span::SourceType;
@@ -944,6 +946,12 @@ impl GlobalObject {
             ($unsigned: ty, $signed: ty, $signed_large: ty) => {{
                 let math_int = <$unsigned>::from_str_radix(z, r).unwrap();
 
+                // 15. If mathInt = 0, then
+                // a. If sign = -1, return -0𝔽.
+                if math_int == 0 && sign == -1 {
+                    return Ok(Value::neg_zero());
+                }
+
                 Ok(if sign == -1 {
                     if math_int <= (<$signed>::MAX as $unsigned) {
                         Value::try_from(-(math_int as $signed)).unwrap()
@@ -996,7 +1004,9 @@ impl GlobalObject {
                     7..11 => parse_known_safe_radix_and_length!(i64, i64, i64),
 
                     _ => {
-                        let math_int = i128::from_str_radix(z, r).unwrap() as f64;
+                        // Note: Z can be arbitrarily long, so parse it as a
+                        // BigUint and round the result to the nearest f64.
+                        let math_int = BigUint::from_str_radix(z, r).unwrap().to_f64().unwrap();
 
                         // 15. If mathInt = 0, then
                         // a. If sign = -1, return -0𝔽.
@@ -742,9 +742,9 @@ impl ArrayPrototype {
             Value::Undefined | Value::Integer(_),
         ) = (this_value, start, end)
         {
-            // Fast path: If the array is simple (no descriptors) and dense (no
+            // Fast path: If the array is trivial (no descriptors) and dense (no
             // holes) then we can write directly into the backing memory.
-            if array.is_simple(agent) && array.is_dense(agent) {
+            if array.is_trivial(agent) && array.is_dense(agent) {
                 let len = array.len(agent) as usize;
 
                 let relative_start = if let Value::Integer(start) = start {
@@ -771,9 +771,11 @@ impl ArrayPrototype {
                     len
                 };
 
-                let data = array.as_mut_slice(agent);
-                data[k..final_end].fill(Some(value));
-                return Ok(value.into_value());
+                if k < final_end {
+                    let data = array.as_mut_slice(agent);
+                    data[k..final_end].fill(Some(value));
+                }
+                return Ok(array.into_value());
             }
         };
         // 1. Let O be ? ToObject(this value).
@@ -1047,15 +1049,18 @@ impl ArrayPrototype {
         // 2. Let sourceLen be ? LengthOfArrayLike(O).
         let source_len = length_of_array_like(agent, o, gc.reborrow())? as usize;
         // 3. Let depthNum be 1.
-        let mut depth_num = 1;
+        let mut depth_num = Some(1);
         // 4. If depth is not undefined, then
         if !depth.is_undefined() {
             // a. Set depthNum to ? ToIntegerOrInfinity(depth).
-            depth_num = to_integer_or_infinity(agent, depth, gc.reborrow())?.into_i64();
-        }
-        // b. If depthNum < 0, set depthNum to 0.
-        if depth_num < 0 {
-            depth_num = 0;
+            let depth = to_integer_or_infinity(agent, depth, gc.reborrow())?;
+            // Note: A depth of +∞ is represented as None.
+            depth_num = if depth.is_pos_infinity() {
+                None
+            } else {
+                // b. If depthNum < 0, set depthNum to 0.
+                Some(depth.into_i64().max(0) as usize)
+            };
         }
         // 5. Let A be ? ArraySpeciesCreate(O, 0).
         let a = array_species_create(agent, o, 0, gc.reborrow())?;
@@ -1066,7 +1071,7 @@ impl ArrayPrototype {
             o,
             source_len,
             0,
-            Some(depth_num as usize),
+            depth_num,
             None,
             None,
             gc.reborrow(),
@@ -1265,6 +1270,9 @@ impl ArrayPrototype {
             } else {
                 0
             };
+            if k >= len as usize {
+                return Ok(false.into());
+            }
             let data = &array.as_slice(agent)[k..];
             let mut found_hole = false;
             for element_k in data {
@@ -1295,7 +1303,7 @@ impl ArrayPrototype {
         // 4. Let n be ? ToIntegerOrInfinity(fromIndex).
         let n = to_integer_or_infinity(agent, from_index, gc.reborrow())?;
         // 5. Assert: If fromIndex is undefined, then n is 0.
-        assert_eq!(from_index.is_undefined(), n.into_i64() == 0);
+        assert!(!from_index.is_undefined() || n.into_i64() == 0);
         // 6. If n = +∞, return false.
         let n = if n.is_pos_infinity() {
             return Ok(false.into());
@@ -1388,6 +1396,9 @@ impl ArrayPrototype {
             } else {
                 0
             };
+            if k >= len as usize {
+                return Ok((-1).into());
+            }
             let data = &array.as_slice(agent)[k..];
             let mut found_hole = false;
             for (index, element_k) in data.iter().enumerate() {
@@ -1418,7 +1429,7 @@ impl ArrayPrototype {
         // 4. Let n be ? ToIntegerOrInfinity(fromIndex).
         let n = to_integer_or_infinity(agent, from_index, gc.reborrow())?;
         // 5. Assert: If fromIndex is undefined, then n is 0.
-        assert_eq!(from_index.is_undefined(), n.into_i64() == 0);
+        assert!(!from_index.is_undefined() || n.into_i64() == 0);
         // 6. If n = +∞, return -1𝔽.
         let n = if n.is_pos_infinity() {
             return Ok((-1).into());
@@ -2776,8 +2787,14 @@ impl ArrayPrototype {
         //       a. Return ? CompareArrayElements(x, y, comparator).
         // 5. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare,
         //     skip-holes).
-        let sorted_list: Vec<Value> =
-            sort_indexed_properties::<true, false>(agent, obj, len, comparator, gc.reborrow())?;
+        let obj = obj.scope(agent, gc.nogc());
+        let sorted_list = sort_indexed_properties::<true, false>(
+            agent,
+            obj.get(agent),
+            len,
+            comparator,
+            gc.reborrow(),
+        )?;
         // 6. Let itemCount be the number of elements in sortedList.
         let item_count = sorted_list.len();
         // 7. Let j be 0.
@@ -2787,9 +2804,9 @@ impl ArrayPrototype {
             // a. Perform ? Set(obj, ! ToString(𝔽(j)), sortedList[j], true).
             set(
                 agent,
-                obj,
+                obj.get(agent),
                 j.try_into().unwrap(),
-                sorted_list[j],
+                sorted_list[j].get(agent),
                 true,
                 gc.reborrow(),
             )?;
@@ -2803,12 +2820,12 @@ impl ArrayPrototype {
         // 10. Repeat, while j < len,
         while j < len {
             // a. Perform ? DeletePropertyOrThrow(obj, ! ToString(𝔽(j))).
-            delete_property_or_throw(agent, obj, j.try_into().unwrap(), gc.reborrow())?;
+            delete_property_or_throw(agent, obj.get(agent), j.try_into().unwrap(), gc.reborrow())?;
             // b. Set j to j + 1.
             j += 1;
         }
         // 11. Return obj.
-        Ok(obj.into_value())
+        Ok(obj.get(agent).into_value())
     }
 
     fn splice(
@@ -3075,7 +3092,7 @@ impl ArrayPrototype {
         //     called:
         //       a. Return ? CompareArrayElements(x, y, comparator).
         // 6. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
-        let sorted_list: Vec<Value> =
+        let sorted_list =
             sort_indexed_properties::<false, false>(agent, o, len, comparator, gc.reborrow())?;
         // 7. Let j be 0.
         // 8. Repeat, while j < len,
@@ -3083,13 +3100,12 @@ impl ArrayPrototype {
         //      b. Set j to j + 1.
         // Fast path: Copy sorted items directly into array.
         let a = a.get(agent);
+        let sorted_list = sorted_list
+            .into_iter()
+            .map(|item| Some(item.get(agent)))
+            .collect::<Vec<Option<Value>>>();
         let slice = a.as_mut_slice(agent);
-        slice.copy_from_slice(
-            &sorted_list
-                .into_iter()
-                .map(Some)
-                .collect::<Vec<Option<Value>>>()[..],
-        );
+        slice.copy_from_slice(&sorted_list[..]);
         // 9. Return A.
         Ok(a.into())
     }
@@ -3611,26 +3627,49 @@ fn find_via_predicate(
 /// optional arguments mapperFunction (a function object) and thisArg (an
 /// ECMAScript language value) and returns either a normal completion
 /// containing a non-negative integer or a throw completion.
+///
+/// The recursive calls of FlattenIntoArray are performed using an explicit
+/// stack so that deeply nested arrays cannot overflow the native stack.
 #[allow(clippy::too_many_arguments)]
-fn flatten_into_array(
+fn flatten_into_array<'scope>(
     agent: &mut Agent,
     target: Object,
     source: Object,
     source_len: usize,
     start: usize,
     depth: Option<usize>,
-    mapper_function: Option<Scoped<'_, Function<'static>>>,
+    mapper_function: Option<Scoped<'scope, Function<'static>>>,
     this_arg: Option<Value>,
-    mut gc: GcScope<'_, '_>,
+    mut gc: GcScope<'_, 'scope>,
 ) -> JsResult<usize> {
     // 1. Assert: If mapperFunction is present, then IsCallable(mapperFunction) is true, thisArg is present, and depth is 1.
     assert!(mapper_function.is_none() || this_arg.is_some() && depth == Some(1));
+    let target = target.scope(agent, gc.nogc());
     // 2. Let targetIndex be start.
     let mut target_index = start;
     // 3. Let sourceIndex be +0𝔽.
-    let mut source_index = 0;
-    // 4. Repeat, while ℝ(sourceIndex) < sourceLen,
-    while source_index < source_len {
+    // Note: Each stack entry holds the source, sourceLen, sourceIndex and
+    // depth of one FlattenIntoArray call. Only the outermost call has a
+    // mapperFunction.
+    let mut stack = vec![FlattenIntoArrayFrame {
+        source: source.scope(agent, gc.nogc()),
+        source_len,
+        source_index: 0,
+        depth,
+    }];
+    while let Some(frame) = stack.last_mut() {
+        // 4. Repeat, while ℝ(sourceIndex) < sourceLen,
+        if frame.source_index >= frame.source_len {
+            // A nested call returns targetIndex to its caller.
+            stack.pop();
+            continue;
+        }
+        let source_index = frame.source_index;
+        let depth = frame.depth;
+        let source = frame.source.get(agent);
+        // d. Set sourceIndex to sourceIndex + 1𝔽.
+        frame.source_index += 1;
+        let is_outermost_call = stack.len() == 1;
         // a. Let P be ! ToString(sourceIndex).
         let source_index_number = Number::try_from(source_index).unwrap();
         let p = PropertyKey::try_from(source_index).unwrap();
@@ -3638,28 +3677,29 @@ fn flatten_into_array(
         let exists = has_property(agent, source, p, gc.reborrow())?;
         // c. If exists is true, then
         if !exists {
-            // d. Set sourceIndex to sourceIndex + 1𝔽.
-            source_index += 1;
             continue;
         }
         // i. Let element be ? Get(source, P).
+        let source = stack.last().unwrap().source.get(agent);
         let element = get(agent, source, p, gc.reborrow())?;
         // ii. If mapperFunction is present, then
-        let element = if let Some(mapper_function) = &mapper_function {
-            // 1. Set element to ? Call(mapperFunction, thisArg, « element, sourceIndex, source »).
-            call_function(
-                agent,
-                mapper_function.get(agent),
-                this_arg.unwrap(),
-                Some(ArgumentsList(&[
-                    element,
-                    source_index_number.into_value(),
-                    source.into_value(),
-                ])),
-                gc.reborrow(),
-            )?
-        } else {
-            element
+        let element = match &mapper_function {
+            Some(mapper_function) if is_outermost_call => {
+                // 1. Set element to ? Call(mapperFunction, thisArg, « element, sourceIndex, source »).
+                let source = stack.last().unwrap().source.get(agent);
+                call_function(
+                    agent,
+                    mapper_function.get(agent),
+                    this_arg.unwrap(),
+                    Some(ArgumentsList(&[
+                        element,
+                        source_index_number.into_value(),
+                        source.into_value(),
+                    ])),
+                    gc.reborrow(),
+                )?
+            }
+            _ => element,
         };
         // iii. Let shouldFlatten be false.
         let mut should_flatten = false;
@@ -3671,22 +3711,18 @@ fn flatten_into_array(
         // v. If shouldFlatten is true, then
         if should_flatten {
             // Note: Element is necessary an Array.
-            let element = Object::try_from(element).unwrap();
+            let element = Object::try_from(element).unwrap().scope(agent, gc.nogc());
             let new_depth = depth.map(|depth| depth - 1);
             // 3. Let elementLen be ? LengthOfArrayLike(element).
-            let element_len = length_of_array_like(agent, element, gc.reborrow())? as usize;
+            let element_len =
+                length_of_array_like(agent, element.get(agent), gc.reborrow())? as usize;
             // 4. Set targetIndex to ? FlattenIntoArray(target, element, elementLen, targetIndex, newDepth).
-            target_index = flatten_into_array(
-                agent,
-                target,
-                element,
-                element_len,
-                target_index,
-                new_depth,
-                None,
-                None,
-                gc.reborrow(),
-            )?;
+            stack.push(FlattenIntoArrayFrame {
+                source: element,
+                source_len: element_len,
+                source_index: 0,
+                depth: new_depth,
+            });
         } else {
             // vi. Else,
             // 1. If targetIndex ≥ 2**53 - 1, throw a TypeError exception.
@@ -3700,20 +3736,27 @@ fn flatten_into_array(
             // 2. Perform ? CreateDataPropertyOrThrow(target, ! ToString(𝔽(targetIndex)), element).
             create_data_property_or_throw(
                 agent,
-                target,
+                target.get(agent),
                 target_index.try_into().unwrap(),
                 element,
                 gc.reborrow(),
             )?;
             // 3. Set targetIndex to targetIndex + 1.
+            target_index += 1;
         }
-        // d. Set sourceIndex to sourceIndex + 1𝔽.
-        source_index += 1;
     }
     // 5. Return targetIndex.
     Ok(target_index)
 }
 
+/// A pending FlattenIntoArray call.
+struct FlattenIntoArrayFrame<'scope> {
+    source: Scoped<'scope, Object>,
+    source_len: usize,
+    source_index: usize,
+    depth: Option<usize>,
+}
+
 /// ### [23.1.3.30.1 SortIndexedProperties ( obj, len, SortCompare, holes )](https://tc39.es/ecma262/#sec-sortindexedproperties)
 ///
 /// The abstract operation SortIndexedProperties takes arguments obj (an
@@ -3767,13 +3810,18 @@ fn flatten_into_array(
 /// > The above conditions are necessary and sufficient to ensure that
 /// > comparator divides the set S into equivalence classes and that these
 /// > equivalence classes are totally ordered.
-fn sort_indexed_properties<const SKIP_HOLES: bool, const TYPED_ARRAY: bool>(
+///
+/// The items are returned as scoped values: the comparator may trigger
+/// garbage collection while the items are being sorted and the caller may
+/// trigger it while writing the sorted items back.
+fn sort_indexed_properties<'scope, const SKIP_HOLES: bool, const TYPED_ARRAY: bool>(
     agent: &mut Agent,
     obj: Object,
     len: usize,
-    comparator: Option<Scoped<'_, Function<'static>>>,
-    mut gc: GcScope<'_, '_>,
-) -> JsResult<Vec<Value>> {
+    comparator: Option<Scoped<'scope, Function<'static>>>,
+    mut gc: GcScope<'_, 'scope>,
+) -> JsResult<Vec<Scoped<'scope, Value>>> {
+    let obj = obj.scope(agent, gc.nogc());
     // 1. Let items be a new empty List.
     let mut items = Vec::with_capacity(len);
     // 2. Let k be 0.
@@ -3785,7 +3833,7 @@ fn sort_indexed_properties<const SKIP_HOLES: bool, const TYPED_ARRAY: bool>(
         // b. If holes is skip-holes, then
         let k_read = if SKIP_HOLES {
             // i. Let kRead be ? HasProperty(obj, Pk).
-            has_property(agent, obj, pk, gc.reborrow())?
+            has_property(agent, obj.get(agent), pk, gc.reborrow())?
         } else {
             // c. Else,
             // i. Assert: holes is read-through-holes.
@@ -3795,9 +3843,9 @@ fn sort_indexed_properties<const SKIP_HOLES: bool, const TYPED_ARRAY: bool>(
         // d. If kRead is true, then
         if k_read {
             // i. Let kValue be ? Get(obj, Pk).
-            let k_value = get(agent, obj, pk, gc.reborrow())?;
+            let k_value = get(agent, obj.get(agent), pk, gc.reborrow())?;
             // ii. Append kValue to items.
-            items.push(k_value);
+            items.push(k_value.scope(agent, gc.nogc()));
         }
         // e. Set k to k + 1.
         k += 1;
@@ -3815,7 +3863,13 @@ fn sort_indexed_properties<const SKIP_HOLES: bool, const TYPED_ARRAY: bool>(
                 // This is dangerous but we don't have much of a choice.
                 return Ordering::Equal;
             }
-            let result = compare_array_elements(agent, *a, *b, comparator.clone(), gc.reborrow());
+            let result = compare_array_elements(
+                agent,
+                a.get(agent),
+                b.get(agent),
+                comparator.clone(),
+                gc.reborrow(),
+            );
             let Ok(result) = result else {
                 error = Some(result.unwrap_err());
                 return Ordering::Equal;
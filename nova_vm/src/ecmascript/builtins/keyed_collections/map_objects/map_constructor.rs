@@ -13,7 +13,9 @@ use crate::{
             operations_on_iterator_objects::{
                 get_iterator, if_abrupt_close_iterator, iterator_close, iterator_step_value,
             },
-            operations_on_objects::{call_function, get, get_method},
+            operations_on_objects::{
+                call_function, create_array_from_scoped_list, get, get_method, group_by_collection,
+            },
             testing_and_comparison::is_callable,
         },
         builders::builtin_function_builder::BuiltinFunctionBuilder,
@@ -22,7 +24,10 @@ use crate::{
             keyed_collections::map_objects::map_prototype::{
                 canonicalize_keyed_collection_key, MapPrototypeSet,
             },
-            map::{data::MapData, Map},
+            map::{
+                data::{MapData, MapHeapData},
+                Map,
+            },
             ordinary::ordinary_create_from_constructor,
             ArgumentsList, Behaviour, Builtin, BuiltinGetter, BuiltinIntrinsicConstructor,
         },
@@ -32,7 +37,9 @@ use crate::{
             BUILTIN_STRING_MEMORY,
         },
     },
-    heap::{Heap, IntrinsicConstructorIndexes, PrimitiveHeap, WellKnownSymbolIndexes},
+    heap::{
+        CreateHeapData, Heap, IntrinsicConstructorIndexes, PrimitiveHeap, WellKnownSymbolIndexes,
+    },
 };
 
 pub(crate) struct MapConstructor;
@@ -49,7 +56,7 @@ impl BuiltinIntrinsicConstructor for MapConstructor {
 struct MapGroupBy;
 impl Builtin for MapGroupBy {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(MapConstructor::group_by);
-    const LENGTH: u8 = 0;
+    const LENGTH: u8 = 2;
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.groupBy;
 }
 struct MapGetSpecies;
@@ -128,13 +135,62 @@ impl MapConstructor {
         }
     }
 
+    /// ### [24.1.2.1 Map.groupBy ( items, callbackfn )](https://tc39.es/ecma262/#sec-map.groupby)
     fn group_by(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
-        _gc: GcScope<'_, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        todo!()
+        let items = arguments.get(0);
+        let callback_fn = arguments.get(1);
+
+        // 1. Let groups be ? GroupBy(items, callback, collection).
+        let groups = group_by_collection(agent, items, callback_fn, gc.reborrow())?;
+
+        // 2. Let map be ! Construct(%Map%).
+        let map = agent.heap.create(MapHeapData::default());
+
+        // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
+        for g in groups {
+            // a. Let elements be CreateArrayFromList(g.[[Elements]]).
+            let elements = create_array_from_scoped_list(agent, g.elements, gc.nogc()).into_value();
+            let key = g.key.get(agent);
+
+            // b. Let entry be the Record { [[Key]]: g.[[Key]], [[Value]]: elements }.
+            // c. Append entry to map.[[MapData]].
+            let Heap {
+                bigints,
+                numbers,
+                strings,
+                maps,
+                ..
+            } = &mut agent.heap;
+            let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
+            let MapData {
+                keys,
+                values,
+                map_data,
+                ..
+            } = maps[map].borrow_mut(&primitive_heap);
+            let map_data = map_data.get_mut();
+            let hasher = |value: Value| {
+                let mut hasher = AHasher::default();
+                value.hash(&primitive_heap, &mut hasher);
+                hasher.finish()
+            };
+            // Note: GroupBy has already merged groups with SameValue keys, so
+            // the key cannot be in the map yet.
+            let index = u32::try_from(keys.len()).unwrap();
+            map_data.insert_unique(hasher(key), index, |index_to_hash| {
+                hasher(keys[*index_to_hash as usize].unwrap())
+            });
+            keys.push(Some(key));
+            values.push(Some(elements));
+        }
+
+        // 4. Return map.
+        Ok(map.into_value())
     }
 
     fn get_species(
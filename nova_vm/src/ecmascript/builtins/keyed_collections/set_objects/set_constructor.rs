@@ -14,11 +14,12 @@ use crate::{
                 get_iterator, if_abrupt_close_iterator, iterator_step_value,
             },
             operations_on_objects::{call_function, get, get_method},
-            testing_and_comparison::is_callable,
+            testing_and_comparison::{is_callable, same_value},
         },
         builders::builtin_function_builder::BuiltinFunctionBuilder,
         builtins::{
             array::ArrayHeap,
+            keyed_collections::map_objects::map_prototype::canonicalize_keyed_collection_key,
             ordinary::ordinary_create_from_constructor,
             set::{data::SetData, Set},
             ArgumentsList, Behaviour, Builtin, BuiltinGetter, BuiltinIntrinsicConstructor,
@@ -145,25 +146,22 @@ impl SetConstructor {
                 assert!(set_data.is_empty());
                 set_data.reserve(iterable_length, |_| unreachable!());
                 iterable.as_slice(&array_heap).iter().for_each(|value| {
-                    let value = value.unwrap();
+                    let value = canonicalize_keyed_collection_key(numbers, value.unwrap());
                     let value_hash = hasher(value);
                     let next_index = values.len() as u32;
                     let entry = set_data.entry(
                         value_hash,
-                        |hash_equal_index| values[*hash_equal_index as usize].unwrap() == value,
+                        |hash_equal_index| {
+                            let found_value = values[*hash_equal_index as usize].unwrap();
+                            found_value == value || same_value(&primitive_heap, found_value, value)
+                        },
                         |index_to_hash| hasher(values[*index_to_hash as usize].unwrap()),
                     );
-                    match entry {
-                        hashbrown::hash_table::Entry::Occupied(occupied) => {
-                            // We have duplicates in the array. Latter
-                            // ones overwrite earlier ones.
-                            let index = *occupied.get();
-                            values[index as usize] = Some(value);
-                        }
-                        hashbrown::hash_table::Entry::Vacant(vacant) => {
-                            vacant.insert(next_index);
-                            values.push(Some(value));
-                        }
+                    // Note: Duplicates in the array are already in the set
+                    // and are skipped, as Set.prototype.add would do.
+                    if let hashbrown::hash_table::Entry::Vacant(vacant) = entry {
+                        vacant.insert(next_index);
+                        values.push(Some(value));
                     }
                 });
                 return Ok(set.into_value());
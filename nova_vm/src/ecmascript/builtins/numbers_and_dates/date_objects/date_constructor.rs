@@ -4,8 +4,13 @@
 
 use std::time::SystemTime;
 
-use crate::ecmascript::abstract_operations::type_conversion::to_number;
+use crate::ecmascript::abstract_operations::type_conversion::{
+    to_number, to_number_primitive, to_primitive, to_string,
+};
 use crate::ecmascript::builders::builtin_function_builder::BuiltinFunctionBuilder;
+use crate::ecmascript::builtins::date::abstract_operations::{
+    make_date, make_day, make_full_year, make_time, parse_date, time_clip, to_date_string, utc,
+};
 use crate::ecmascript::builtins::date::Date;
 use crate::ecmascript::builtins::ordinary::ordinary_create_from_constructor;
 use crate::ecmascript::builtins::ArgumentsList;
@@ -18,14 +23,11 @@ use crate::ecmascript::execution::ProtoIntrinsics;
 use crate::ecmascript::execution::RealmIdentifier;
 use crate::ecmascript::types::Function;
 use crate::ecmascript::types::IntoObject;
-use crate::ecmascript::types::IntoValue;
-use crate::ecmascript::types::Number;
 use crate::ecmascript::types::Object;
 use crate::ecmascript::types::BUILTIN_STRING_MEMORY;
 use crate::ecmascript::types::{String, Value};
 use crate::engine::context::GcScope;
 use crate::heap::IntrinsicConstructorIndexes;
-use crate::SmallInteger;
 
 pub struct DateConstructor;
 
@@ -54,9 +56,10 @@ struct DateUTC;
 impl Builtin for DateUTC {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(DateConstructor::utc);
     const LENGTH: u8 = 7;
-    const NAME: String<'static> = BUILTIN_STRING_MEMORY.utc;
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.UTC;
 }
 impl DateConstructor {
+    /// ### [21.4.2.1 Date ( ...values )](https://tc39.es/ecma262/#sec-date)
     fn constructor(
         agent: &mut Agent,
         _this_value: Value,
@@ -67,9 +70,10 @@ impl DateConstructor {
         // 1. If NewTarget is undefined, then
         let Some(new_target) = new_target else {
             // a. Let now be the time value (UTC) identifying the current time.
-            let _now = SystemTime::now();
+            let now = time_value_now();
             // b. Return ToDateString(now).
-            todo!("ToDateString(now)");
+            let date_string = to_date_string(now).unwrap();
+            return Ok(String::from_string(agent, date_string, gc.nogc()).into_value());
         };
         // 2. Let numberOfArgs be the number of elements in values.
         let number_of_args = arguments.len() as u32;
@@ -77,26 +81,38 @@ impl DateConstructor {
             // 3. If numberOfArgs = 0, then
             0 => {
                 // a. Let dv be the time value (UTC) identifying the current time.
-                SystemTime::now()
+                time_value_now()
             }
             // 4. Else if numberOfArgs = 1, then
             1 => {
-                todo!();
                 // a. Let value be values[0].
+                let value = arguments.get(0);
                 // b. If value is an Object and value has a [[DateValue]] internal slot, then
-                // i. Let tv be value.[[DateValue]].
-                // c. Else,
-                // i. Let v be ? ToPrimitive(value).
-                // ii. If v is a String, then
-                // 1. Assert: The next step never returns an abrupt completion because v is a String.
-                // 2. Let tv be the result of parsing v as a date, in exactly the same manner as for the parse method (21.4.3.2).
-                // iii. Else,
-                // 1. Let tv be ? ToNumber(v).
+                let tv = if let Value::Date(value) = value {
+                    // i. Let tv be value.[[DateValue]].
+                    value.date_value(agent)
+                } else {
+                    // c. Else,
+                    // i. Let v be ? ToPrimitive(value).
+                    let v = to_primitive(agent, value, None, gc.reborrow())?
+                        .unbind()
+                        .bind(gc.nogc());
+                    // ii. If v is a String, then
+                    if let Ok(v) = String::try_from(v) {
+                        // 1. Assert: The next step never returns an abrupt completion because v is a String.
+                        // 2. Let tv be the result of parsing v as a date, in exactly the same manner as for the parse method (21.4.3.2).
+                        parse_date(v.as_str(agent))
+                    } else {
+                        // iii. Else,
+                        // 1. Let tv be ? ToNumber(v).
+                        to_number_primitive(agent, v, gc.nogc())?.into_f64(agent)
+                    }
+                };
                 // d. Let dv be TimeClip(tv).
+                time_clip(tv)
             }
             // 5. Else,
             _ => {
-                todo!();
                 // a. Assert: numberOfArgs ≥ 2.
                 // b. Let y be ? ToNumber(values[0]).
                 // c. Let m be ? ToNumber(values[1]).
@@ -107,7 +123,9 @@ impl DateConstructor {
                 // h. If numberOfArgs > 6, let milli be ? ToNumber(values[6]); else let milli be +0𝔽.
                 // i. Let yr be MakeFullYear(y).
                 // j. Let finalDate be MakeDate(MakeDay(yr, m, dt), MakeTime(h, min, s, milli)).
+                let final_date = date_from_components(agent, arguments, gc.reborrow())?;
                 // k. Let dv be TimeClip(UTC(finalDate)).
+                time_clip(utc(final_date))
             }
         };
 
@@ -119,94 +137,59 @@ impl DateConstructor {
             gc.reborrow(),
         )?;
         // 7. Set O.[[DateValue]] to dv.
-        agent[Date::try_from(o).unwrap()].date = Some(dv);
+        Date::try_from(o).unwrap().set_date_value(agent, dv);
         // 8. Return O.
         Ok(o.into_value())
     }
 
-    /// ### [21.1.2.2 Number.isFinite ( number )](https://tc39.es/ecma262/#sec-number.isfinite)
+    /// ### [21.4.3.1 Date.now ( )](https://tc39.es/ecma262/#sec-date.now)
     fn now(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
         _arguments: ArgumentsList,
-        _gc: GcScope<'_, '_>,
+        gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let time_value = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_millis();
-        assert_eq!(time_value as u64 as u128, time_value);
-        let time_value = time_value as u64;
-        Ok(
-            Number::from(SmallInteger::try_from(time_value).expect("SystemTime is beyond range"))
-                .into_value(),
-        )
+        Ok(Value::from_f64(agent, time_value_now(), gc.nogc()))
     }
 
-    /// ### [21.1.2.3 Number.isInteger ( number )](https://tc39.es/ecma262/#sec-number.isinteger)
+    /// ### [21.4.3.2 Date.parse ( string )](https://tc39.es/ecma262/#sec-date.parse)
     fn parse(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
-        _gc: GcScope<'_, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        todo!();
+        let string = to_string(agent, arguments.get(0), gc.reborrow())?
+            .unbind()
+            .bind(gc.nogc());
+        let tv = parse_date(string.as_str(agent));
+        Ok(Value::from_f64(agent, tv, gc.nogc()))
     }
 
     /// ### [21.4.3.4 Date.UTC ( year \[ , month \[ , date \[ , hours \[ , minutes \[ , seconds \[ , ms \] \] \] \] \] \] )](https://tc39.es/ecma262/#sec-date.utc)
+    ///
+    /// > #### Note
+    /// >
+    /// > This function differs from the Date constructor in two ways: it
+    /// > returns a time value as a Number, rather than creating a Date, and it
+    /// > interprets the arguments in UTC rather than as local time.
     fn utc(
         agent: &mut Agent,
         _this_value: Value,
         arguments: ArgumentsList,
         mut gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _ns = arguments.get(0);
         // 1. Let y be ? ToNumber(year).
-        let _y = to_number(agent, arguments.get(0), gc.reborrow())?;
         // 2. If month is present, let m be ? ToNumber(month); else let m be +0𝔽.
-        let _m = if arguments.len() > 1 {
-            to_number(agent, arguments.get(1), gc.reborrow())?
-        } else {
-            0.into()
-        };
         // 3. If date is present, let dt be ? ToNumber(date); else let dt be 1𝔽.
-        let _dt = if arguments.len() > 2 {
-            to_number(agent, arguments.get(2), gc.reborrow())?
-        } else {
-            0.into()
-        };
         // 4. If hours is present, let h be ? ToNumber(hours); else let h be +0𝔽.
-        let _h = if arguments.len() > 3 {
-            to_number(agent, arguments.get(3), gc.reborrow())?
-        } else {
-            0.into()
-        };
         // 5. If minutes is present, let min be ? ToNumber(minutes); else let min be +0𝔽.
-        let _min = if arguments.len() > 4 {
-            to_number(agent, arguments.get(4), gc.reborrow())?
-        } else {
-            0.into()
-        };
         // 6. If seconds is present, let s be ? ToNumber(seconds); else let s be +0𝔽.
-        let _s = if arguments.len() > 5 {
-            to_number(agent, arguments.get(5), gc.reborrow())?
-        } else {
-            0.into()
-        };
         // 7. If ms is present, let milli be ? ToNumber(ms); else let milli be +0𝔽.
-        let _milli = if arguments.len() > 6 {
-            to_number(agent, arguments.get(6), gc.reborrow())?
-        } else {
-            0.into()
-        };
         // 8. Let yr be MakeFullYear(y).
-        todo!("MakeFullYear");
+        let date = date_from_components(agent, arguments, gc.reborrow())?;
         // 9. Return TimeClip(MakeDate(MakeDay(yr, m, dt), MakeTime(h, min, s, milli))).
-
-        // Note
-        // This function differs from the Date constructor in two ways: it
-        // returns a time value as a Number, rather than creating a Date,
-        // and it interprets the arguments in UTC rather than as local time.
+        Ok(Value::from_f64(agent, time_clip(date), gc.nogc()))
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: RealmIdentifier) {
@@ -222,3 +205,32 @@ impl DateConstructor {
             .build();
     }
 }
+
+/// The time value identifying the current time.
+fn time_value_now() -> f64 {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("SystemTime is before the epoch");
+    now.as_millis() as f64
+}
+
+/// Converts the year, month, date, hours, minutes, seconds and milliseconds
+/// arguments of the Date constructor and Date.UTC to Numbers and combines
+/// them into a date with MakeDate. Missing arguments default to the first
+/// day of the month at midnight.
+fn date_from_components(
+    agent: &mut Agent,
+    arguments: ArgumentsList,
+    mut gc: GcScope<'_, '_>,
+) -> JsResult<f64> {
+    let mut components = [f64::NAN, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+    for (index, component) in components.iter_mut().enumerate() {
+        // The year is always converted, even if it is not present.
+        if index == 0 || index < arguments.len() {
+            *component = to_number(agent, arguments.get(index), gc.reborrow())?.into_f64(agent);
+        }
+    }
+    let [y, m, dt, h, min, s, milli] = components;
+    let yr = make_full_year(y);
+    Ok(make_date(make_day(yr, m, dt), make_time(h, min, s, milli)))
+}
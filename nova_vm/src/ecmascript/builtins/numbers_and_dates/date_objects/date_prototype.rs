@@ -2,19 +2,34 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::time::SystemTime;
+use std::ops::RangeInclusive;
 
 use crate::engine::context::{GcScope, NoGcScope};
 use crate::{
     ecmascript::{
-        abstract_operations::type_conversion::{ordinary_to_primitive, PreferredType},
+        abstract_operations::{
+            operations_on_objects::invoke,
+            type_conversion::{
+                ordinary_to_primitive, to_number, to_object, to_primitive, PreferredType,
+            },
+        },
         builders::ordinary_object_builder::OrdinaryObjectBuilder,
-        builtins::{date::Date, ArgumentsList, Behaviour, Builtin, BuiltinIntrinsic},
+        builtins::{
+            date::{
+                abstract_operations::{
+                    date_from_time, date_string, hour_from_time, iso_string, local_time, make_date,
+                    make_day, make_time, min_from_time, month_from_time, ms_from_time,
+                    sec_from_time, time_clip, time_string, time_zone_string, to_date_string, utc,
+                    utc_string, week_day, year_from_time, MS_PER_MINUTE,
+                },
+                Date,
+            },
+            ArgumentsList, Behaviour, Builtin, BuiltinIntrinsic,
+        },
         execution::{agent::ExceptionType, Agent, JsResult, RealmIdentifier},
         types::{IntoValue, Number, Object, PropertyKey, String, Value, BUILTIN_STRING_MEMORY},
     },
     heap::{IntrinsicFunctionIndexes, WellKnownSymbolIndexes},
-    SmallInteger,
 };
 
 pub(crate) struct DatePrototype;
@@ -295,470 +310,647 @@ impl Builtin for DatePrototypeToPrimitive {
     const WRITABLE: bool = false;
 }
 
-const MAX_SYSTEM_TIME_VALUE: u128 = SmallInteger::MAX_NUMBER as u128;
-
 impl DatePrototype {
+    /// ### [21.4.4.2 Date.prototype.getDate ( )](https://tc39.es/ecma262/#sec-date.prototype.getdate)
     fn get_date(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, true, date_from_time, gc)
     }
 
+    /// ### [21.4.4.3 Date.prototype.getDay ( )](https://tc39.es/ecma262/#sec-date.prototype.getday)
     fn get_day(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, true, week_day, gc)
     }
 
+    /// ### [21.4.4.4 Date.prototype.getFullYear ( )](https://tc39.es/ecma262/#sec-date.prototype.getfullyear)
     fn get_full_year(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, true, year_from_time, gc)
     }
 
+    /// ### [21.4.4.5 Date.prototype.getHours ( )](https://tc39.es/ecma262/#sec-date.prototype.gethours)
     fn get_hours(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, true, hour_from_time, gc)
     }
 
+    /// ### [21.4.4.6 Date.prototype.getMilliseconds ( )](https://tc39.es/ecma262/#sec-date.prototype.getmilliseconds)
     fn get_milliseconds(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, true, ms_from_time, gc)
     }
 
+    /// ### [21.4.4.7 Date.prototype.getMinutes ( )](https://tc39.es/ecma262/#sec-date.prototype.getminutes)
     fn get_minutes(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, true, min_from_time, gc)
     }
 
+    /// ### [21.4.4.8 Date.prototype.getMonth ( )](https://tc39.es/ecma262/#sec-date.prototype.getmonth)
     fn get_month(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, true, month_from_time, gc)
     }
 
+    /// ### [21.4.4.9 Date.prototype.getSeconds ( )](https://tc39.es/ecma262/#sec-date.prototype.getseconds)
     fn get_seconds(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, true, sec_from_time, gc)
     }
 
+    /// ### [21.4.4.10 Date.prototype.getTime ( )](https://tc39.es/ecma262/#sec-date.prototype.gettime)
     fn get_time(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        let date_object = check_date_object(agent, this_value, gc.nogc())?;
+        // 3. Return dateObject.[[DateValue]].
+        let t = date_object.date_value(agent);
+        Ok(Value::from_f64(agent, t, gc.nogc()))
     }
 
+    /// ### [21.4.4.11 Date.prototype.getTimezoneOffset ( )](https://tc39.es/ecma262/#sec-date.prototype.gettimezoneoffset)
     fn get_timezone_offset(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        let date_object = check_date_object(agent, this_value, gc.nogc())?;
+        // 3. Let t be dateObject.[[DateValue]].
+        let t = date_object.date_value(agent);
+        // 4. If t is NaN, return NaN.
+        if t.is_nan() {
+            return Ok(Value::nan());
+        }
+        // 5. Return (t - LocalTime(t)) / msPerMinute.
+        let offset = (t - local_time(t)) / MS_PER_MINUTE;
+        Ok(Value::from_f64(agent, offset, gc.nogc()))
     }
 
+    /// ### [21.4.4.12 Date.prototype.getUTCDate ( )](https://tc39.es/ecma262/#sec-date.prototype.getutcdate)
     fn get_utc_date(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, false, date_from_time, gc)
     }
 
+    /// ### [21.4.4.13 Date.prototype.getUTCDay ( )](https://tc39.es/ecma262/#sec-date.prototype.getutcday)
     fn get_utc_day(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, false, week_day, gc)
     }
 
+    /// ### [21.4.4.14 Date.prototype.getUTCFullYear ( )](https://tc39.es/ecma262/#sec-date.prototype.getutcfullyear)
     fn get_utc_full_year(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, false, year_from_time, gc)
     }
 
+    /// ### [21.4.4.15 Date.prototype.getUTCHours ( )](https://tc39.es/ecma262/#sec-date.prototype.getutchours)
     fn get_utc_hours(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, false, hour_from_time, gc)
     }
 
+    /// ### [21.4.4.16 Date.prototype.getUTCMilliseconds ( )](https://tc39.es/ecma262/#sec-date.prototype.getutcmilliseconds)
     fn get_utc_milliseconds(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, false, ms_from_time, gc)
     }
 
+    /// ### [21.4.4.17 Date.prototype.getUTCMinutes ( )](https://tc39.es/ecma262/#sec-date.prototype.getutcminutes)
     fn get_utc_minutes(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, false, min_from_time, gc)
     }
 
+    /// ### [21.4.4.18 Date.prototype.getUTCMonth ( )](https://tc39.es/ecma262/#sec-date.prototype.getutcmonth)
     fn get_utc_month(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, false, month_from_time, gc)
     }
 
+    /// ### [21.4.4.19 Date.prototype.getUTCSeconds ( )](https://tc39.es/ecma262/#sec-date.prototype.getutcseconds)
     fn get_utc_seconds(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        get_date_component(agent, this_value, false, sec_from_time, gc)
     }
 
+    /// ### [21.4.4.20 Date.prototype.setDate ( date )](https://tc39.es/ecma262/#sec-date.prototype.setdate)
     fn set_date(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            true,
+            DateComponent::Date..=DateComponent::Date,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.21 Date.prototype.setFullYear ( year \\[ , month \\[ , date \\] \\] )](https://tc39.es/ecma262/#sec-date.prototype.setfullyear)
     fn set_full_year(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            true,
+            DateComponent::Year..=DateComponent::Date,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.22 Date.prototype.setHours ( hour \\[ , min \\[ , sec \\[ , ms \\] \\] \\] )](https://tc39.es/ecma262/#sec-date.prototype.sethours)
     fn set_hours(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            true,
+            DateComponent::Hours..=DateComponent::Milliseconds,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.23 Date.prototype.setMilliseconds ( ms )](https://tc39.es/ecma262/#sec-date.prototype.setmilliseconds)
     fn set_milliseconds(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            true,
+            DateComponent::Milliseconds..=DateComponent::Milliseconds,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.24 Date.prototype.setMinutes ( min \\[ , sec \\[ , ms \\] \\] )](https://tc39.es/ecma262/#sec-date.prototype.setminutes)
     fn set_minutes(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            true,
+            DateComponent::Minutes..=DateComponent::Milliseconds,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.25 Date.prototype.setMonth ( month \\[ , date \\] )](https://tc39.es/ecma262/#sec-date.prototype.setmonth)
     fn set_month(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            true,
+            DateComponent::Month..=DateComponent::Date,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.26 Date.prototype.setSeconds ( sec \\[ , ms \\] )](https://tc39.es/ecma262/#sec-date.prototype.setseconds)
     fn set_seconds(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            true,
+            DateComponent::Seconds..=DateComponent::Milliseconds,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.27 Date.prototype.setTime ( time )](https://tc39.es/ecma262/#sec-date.prototype.settime)
     fn set_time(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
-        gc: GcScope<'_, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        let date_object = check_date_object(agent, this_value, gc.nogc())?.scope(agent, gc.nogc());
+        // 3. Let t be ? ToNumber(time).
+        let t = to_number(agent, arguments.get(0), gc.reborrow())?.into_f64(agent);
+        // 4. Let v be TimeClip(t).
+        let v = time_clip(t);
+        // 5. Set dateObject.[[DateValue]] to v.
+        date_object.get(agent).set_date_value(agent, v);
+        // 6. Return v.
+        Ok(Value::from_f64(agent, v, gc.nogc()))
     }
 
+    /// ### [21.4.4.28 Date.prototype.setUTCDate ( date )](https://tc39.es/ecma262/#sec-date.prototype.setutcdate)
     fn set_utc_date(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            false,
+            DateComponent::Date..=DateComponent::Date,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.29 Date.prototype.setUTCFullYear ( year \\[ , month \\[ , date \\] \\] )](https://tc39.es/ecma262/#sec-date.prototype.setutcfullyear)
     fn set_utc_full_year(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            false,
+            DateComponent::Year..=DateComponent::Date,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.30 Date.prototype.setUTCHours ( hour \\[ , min \\[ , sec \\[ , ms \\] \\] \\] )](https://tc39.es/ecma262/#sec-date.prototype.setutchours)
     fn set_utc_hours(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            false,
+            DateComponent::Hours..=DateComponent::Milliseconds,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.31 Date.prototype.setUTCMilliseconds ( ms )](https://tc39.es/ecma262/#sec-date.prototype.setutcmilliseconds)
     fn set_utc_milliseconds(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            false,
+            DateComponent::Milliseconds..=DateComponent::Milliseconds,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.32 Date.prototype.setUTCMinutes ( min \\[ , sec \\[ , ms \\] \\] )](https://tc39.es/ecma262/#sec-date.prototype.setutcminutes)
     fn set_utc_minutes(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            false,
+            DateComponent::Minutes..=DateComponent::Milliseconds,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.33 Date.prototype.setUTCMonth ( month \\[ , date \\] )](https://tc39.es/ecma262/#sec-date.prototype.setutcmonth)
     fn set_utc_month(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            false,
+            DateComponent::Month..=DateComponent::Date,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.34 Date.prototype.setUTCSeconds ( sec \\[ , ms \\] )](https://tc39.es/ecma262/#sec-date.prototype.setutcseconds)
     fn set_utc_seconds(
         agent: &mut Agent,
         this_value: Value,
-        _: ArgumentsList,
+        arguments: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        set_date_components(
+            agent,
+            this_value,
+            arguments,
+            false,
+            DateComponent::Seconds..=DateComponent::Milliseconds,
+            gc,
+        )
     }
 
+    /// ### [21.4.4.35 Date.prototype.toDateString ( )](https://tc39.es/ecma262/#sec-date.prototype.todatestring)
     fn to_date_string(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        let date_object = check_date_object(agent, this_value, gc.nogc())?;
+        // 3. Let tv be dateObject.[[DateValue]].
+        let tv = date_object.date_value(agent);
+        // 4. If tv is NaN, return "Invalid Date".
+        // 5. Let t be LocalTime(tv).
+        // 6. Return DateString(t).
+        let result = (!tv.is_nan()).then(|| date_string(local_time(tv)));
+        Ok(date_string_result(agent, result, gc.nogc()))
     }
 
+    /// ### [21.4.4.36 Date.prototype.toISOString ( )](https://tc39.es/ecma262/#sec-date.prototype.toisostring)
     fn to_iso_string(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        let date_object = check_date_object(agent, this_value, gc.nogc())?;
+        // 3. Let tv be dateObject.[[DateValue]].
+        let tv = date_object.date_value(agent);
+        // 4. If tv is not finite, throw a RangeError exception.
+        if !tv.is_finite() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Invalid time value",
+                gc.nogc(),
+            ));
+        }
+        // 5. Assert: tv is an integral Number.
+        // 6. If tv corresponds with a year that cannot be represented in the
+        //    Date Time String Format, throw a RangeError exception.
+        // 7. Return a String representation of tv in the Date Time String
+        //    Format on the UTC time scale, including all format elements and
+        //    the UTC offset representation "Z".
+        Ok(String::from_string(agent, iso_string(tv), gc.nogc()).into_value())
     }
 
+    /// ### [21.4.4.37 Date.prototype.toJSON ( key )](https://tc39.es/ecma262/#sec-date.prototype.tojson)
     fn to_json(
-        _agent: &mut Agent,
-        _this_value: Value,
+        agent: &mut Agent,
+        this_value: Value,
         _: ArgumentsList,
-        _gc: GcScope<'_, '_>,
+        mut gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        todo!()
+        // 1. Let O be ? ToObject(this value).
+        let o = to_object(agent, this_value, gc.nogc())?.scope(agent, gc.nogc());
+        // 2. Let tv be ? ToPrimitive(O, number).
+        let tv = to_primitive(
+            agent,
+            o.get(agent),
+            Some(PreferredType::Number),
+            gc.reborrow(),
+        )?;
+        // 3. If tv is a Number and tv is not finite, return null.
+        if let Ok(tv) = Number::try_from(tv) {
+            if !tv.into_f64(agent).is_finite() {
+                return Ok(Value::Null);
+            }
+        }
+        // 4. Return ? Invoke(O, "toISOString").
+        invoke(
+            agent,
+            o.get(agent).into_value(),
+            BUILTIN_STRING_MEMORY.toISOString.into(),
+            None,
+            gc.reborrow(),
+        )
     }
 
+    /// ### [21.4.4.38 Date.prototype.toLocaleDateString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-date.prototype.tolocaledatestring)
+    ///
+    /// Without the ECMA-402 Internationalization API the format is
+    /// implementation-defined; Nova uses the format of toDateString.
     fn to_locale_date_string(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'_, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        todo!()
+        Self::to_date_string(agent, this_value, arguments, gc)
     }
 
+    /// ### [21.4.4.39 Date.prototype.toLocaleString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-date.prototype.tolocalestring)
+    ///
+    /// Without the ECMA-402 Internationalization API the format is
+    /// implementation-defined; Nova uses the format of toString.
     fn to_locale_string(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'_, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        todo!()
+        Self::to_string(agent, this_value, arguments, gc)
     }
 
+    /// ### [21.4.4.40 Date.prototype.toLocaleTimeString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-date.prototype.tolocaletimestring)
+    ///
+    /// Without the ECMA-402 Internationalization API the format is
+    /// implementation-defined; Nova uses the format of toTimeString.
     fn to_locale_time_string(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope<'_, '_>,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        todo!()
+        Self::to_time_string(agent, this_value, arguments, gc)
     }
 
+    /// ### [21.4.4.41 Date.prototype.toString ( )](https://tc39.es/ecma262/#sec-date.prototype.tostring)
     fn to_string(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        let date_object = check_date_object(agent, this_value, gc.nogc())?;
+        // 3. Let tv be dateObject.[[DateValue]].
+        let tv = date_object.date_value(agent);
+        // 4. Return ToDateString(tv).
+        let result = to_date_string(tv);
+        Ok(date_string_result(agent, result, gc.nogc()))
     }
 
+    /// ### [21.4.4.42 Date.prototype.toTimeString ( )](https://tc39.es/ecma262/#sec-date.prototype.totimestring)
     fn to_time_string(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        let date_object = check_date_object(agent, this_value, gc.nogc())?;
+        // 3. Let tv be dateObject.[[DateValue]].
+        let tv = date_object.date_value(agent);
+        // 4. If tv is NaN, return "Invalid Date".
+        // 5. Let t be LocalTime(tv).
+        // 6. Return the string-concatenation of TimeString(t) and TimeZoneString(tv).
+        let result = (!tv.is_nan())
+            .then(|| format!("{}{}", time_string(local_time(tv)), time_zone_string(tv)));
+        Ok(date_string_result(agent, result, gc.nogc()))
     }
 
+    /// ### [21.4.4.43 Date.prototype.toUTCString ( )](https://tc39.es/ecma262/#sec-date.prototype.toutcstring)
     fn to_utc_string(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        let _date_object = check_date_object(agent, this_value, gc.nogc())?;
-        todo!()
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        let date_object = check_date_object(agent, this_value, gc.nogc())?;
+        // 3. Let tv be dateObject.[[DateValue]].
+        let tv = date_object.date_value(agent);
+        // 4. If tv is NaN, return "Invalid Date".
+        let result = (!tv.is_nan()).then(|| utc_string(tv));
+        Ok(date_string_result(agent, result, gc.nogc()))
     }
 
+    /// ### [21.4.4.44 Date.prototype.valueOf ( )](https://tc39.es/ecma262/#sec-date.prototype.valueof)
     fn value_of(
         agent: &mut Agent,
         this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
         let date_object = check_date_object(agent, this_value, gc.nogc())?;
-        let data = &agent[date_object].date;
-        match data {
-            Some(system_time) => {
-                let time_as_millis = system_time
-                    .duration_since(SystemTime::UNIX_EPOCH)
-                    .map_or_else(
-                        |_| {
-                            // System time is before UNIX_EPOCH
-                            let value = SystemTime::UNIX_EPOCH
-                                .duration_since(*system_time)
-                                .unwrap()
-                                .as_millis();
-                            if value > MAX_SYSTEM_TIME_VALUE {
-                                // Time difference is over representable limit
-                                None
-                            } else {
-                                Some(-(value as i64))
-                            }
-                        },
-                        |value| {
-                            let value = value.as_millis();
-                            if value > MAX_SYSTEM_TIME_VALUE {
-                                None
-                            } else {
-                                Some(value as i64)
-                            }
-                        },
-                    );
-                match time_as_millis {
-                    Some(time_as_millis) => Ok(Number::from(
-                        SmallInteger::try_from(time_as_millis).unwrap(),
-                    )
-                    .into_value()),
-                    None => Ok(Value::nan()),
-                }
-            }
-            None => Ok(Value::nan()),
-        }
+        // 3. Return dateObject.[[DateValue]].
+        let t = date_object.date_value(agent);
+        Ok(Value::from_f64(agent, t, gc.nogc()))
     }
 
     /// ### [21.4.4.45 Date.prototype \[ %Symbol.toPrimitive% \] ( hint )](https://tc39.es/ecma262/#sec-date.prototype-%symbol.toprimitive%)
@@ -877,3 +1069,115 @@ fn check_date_object(agent: &mut Agent, this_value: Value, gc: NoGcScope) -> JsR
         )),
     }
 }
+
+/// Shared steps of the Date.prototype getters: returns the component of the
+/// Date's time value, in local time or UTC, or NaN for an invalid Date.
+fn get_date_component(
+    agent: &mut Agent,
+    this_value: Value,
+    local: bool,
+    component: fn(f64) -> f64,
+    gc: GcScope<'_, '_>,
+) -> JsResult<Value> {
+    // 1. Let dateObject be the this value.
+    // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+    let date_object = check_date_object(agent, this_value, gc.nogc())?;
+    // 3. Let t be dateObject.[[DateValue]].
+    let t = date_object.date_value(agent);
+    // 4. If t is NaN, return NaN.
+    if t.is_nan() {
+        return Ok(Value::nan());
+    }
+    // 5. Return the component of LocalTime(t) or of t.
+    let t = if local { local_time(t) } else { t };
+    Ok(Value::from_f64(agent, component(t), gc.nogc()))
+}
+
+/// The components of a time value, in the order of the Date.prototype setter
+/// arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum DateComponent {
+    Year,
+    Month,
+    Date,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+}
+
+/// Shared steps of the Date.prototype setters other than setTime.
+///
+/// The first component in the range is always set from the first argument;
+/// the others are set from the following arguments if they are present and
+/// otherwise keep their current values.
+fn set_date_components(
+    agent: &mut Agent,
+    this_value: Value,
+    arguments: ArgumentsList,
+    local: bool,
+    components: RangeInclusive<DateComponent>,
+    mut gc: GcScope<'_, '_>,
+) -> JsResult<Value> {
+    // 1. Let dateObject be the this value.
+    // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+    let date_object = check_date_object(agent, this_value, gc.nogc())?.scope(agent, gc.nogc());
+    // 3. Let t be dateObject.[[DateValue]].
+    let t = date_object.get(agent).date_value(agent);
+    // 4. Let y be ? ToNumber(year), etc. Each following argument is only
+    //    converted if it is present.
+    let first = *components.start() as usize;
+    let last = *components.end() as usize;
+    let mut values = [None; 7];
+    for (index, value) in values[first..=last].iter_mut().enumerate() {
+        if index == 0 || index < arguments.len() {
+            *value = Some(to_number(agent, arguments.get(index), gc.reborrow())?.into_f64(agent));
+        }
+    }
+    let t = if t.is_nan() {
+        // 5. If t is NaN, set t to +0𝔽; otherwise, set t to LocalTime(t).
+        // Note: Only setFullYear and setUTCFullYear recover from an invalid
+        // Date; the other setters return NaN.
+        if *components.start() != DateComponent::Year {
+            return Ok(Value::nan());
+        }
+        0.0
+    } else if local {
+        local_time(t)
+    } else {
+        t
+    };
+    // 6. If month is not present, let m be MonthFromTime(t), etc.
+    let [year, month, date, hours, minutes, seconds, milliseconds] = values;
+    let year = year.unwrap_or_else(|| year_from_time(t));
+    let month = month.unwrap_or_else(|| month_from_time(t));
+    let date = date.unwrap_or_else(|| date_from_time(t));
+    let hours = hours.unwrap_or_else(|| hour_from_time(t));
+    let minutes = minutes.unwrap_or_else(|| min_from_time(t));
+    let seconds = seconds.unwrap_or_else(|| sec_from_time(t));
+    let milliseconds = milliseconds.unwrap_or_else(|| ms_from_time(t));
+    // 7. Let newDate be MakeDate(MakeDay(y, m, dt), MakeTime(h, min, s, milli)).
+    let new_date = make_date(
+        make_day(year, month, date),
+        make_time(hours, minutes, seconds, milliseconds),
+    );
+    // 8. Let u be TimeClip(UTC(newDate)).
+    let u = time_clip(if local { utc(new_date) } else { new_date });
+    // 9. Set dateObject.[[DateValue]] to u.
+    date_object.get(agent).set_date_value(agent, u);
+    // 10. Return u.
+    Ok(Value::from_f64(agent, u, gc.nogc()))
+}
+
+/// Converts the result of a Date string method to a String value, using
+/// "Invalid Date" for invalid Dates.
+fn date_string_result(
+    agent: &mut Agent,
+    result: Option<std::string::String>,
+    gc: NoGcScope,
+) -> Value {
+    match result {
+        Some(result) => String::from_string(agent, result, gc).into_value(),
+        None => String::from_static_str(agent, "Invalid Date", gc).into_value(),
+    }
+}
@@ -2,12 +2,19 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use std::fmt::Write;
+
+use ahash::AHashSet;
 use sonic_rs::{JsonContainerTrait, JsonValueTrait};
 
 use crate::ecmascript::abstract_operations::operations_on_objects::{
-    length_of_array_like, try_create_data_property, try_create_data_property_or_throw,
+    get_v, length_of_array_like, try_create_data_property, try_create_data_property_or_throw,
 };
 use crate::ecmascript::abstract_operations::testing_and_comparison::is_array;
+use crate::ecmascript::abstract_operations::type_conversion::{
+    to_integer_or_infinity_number, to_number,
+};
+use crate::ecmascript::builtins::primitive_objects::PrimitiveObjectData;
 use crate::ecmascript::types::{IntoObject, IntoValue};
 use crate::engine::context::{GcScope, NoGcScope};
 use crate::engine::{unwrap_try, Scoped};
@@ -26,7 +33,7 @@ use crate::{
         },
         execution::{agent::ExceptionType, Agent, JsResult, ProtoIntrinsics, RealmIdentifier},
         types::{
-            Function, InternalMethods, Number, Object, PropertyKey, String, Value,
+            BigInt, Function, InternalMethods, Number, Object, PropertyKey, String, Value,
             BUILTIN_STRING_MEMORY,
         },
     },
@@ -166,13 +173,173 @@ impl JSONObject {
         Ok(unfiltered)
     }
 
+    /// ### [25.5.2 JSON.stringify ( value \[ , replacer \[ , space \] \] )](https://tc39.es/ecma262/#sec-json.stringify)
+    ///
+    /// This function returns a String in UTF-16 encoded JSON format
+    /// representing an ECMAScript language value, or undefined. It can take
+    /// three parameters. The value parameter is an ECMAScript language value,
+    /// which is usually an object or array, although it can also be a String,
+    /// Boolean, Number or null. The optional replacer parameter is either a
+    /// function that alters the way objects and arrays are stringified, or an
+    /// array of Strings and Numbers that acts as an inclusion list for
+    /// selecting the object properties that will be stringified. The optional
+    /// space parameter is a String or Number that allows the result to have
+    /// white space injected into it to improve human readability.
     fn stringify(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _arguments: ArgumentsList,
-        _gc: GcScope<'_, '_>,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'_, '_>,
     ) -> JsResult<Value> {
-        todo!();
+        let value = arguments.get(0).scope(agent, gc.nogc());
+        let replacer = arguments.get(1);
+        let space = arguments.get(2).scope(agent, gc.nogc());
+
+        // 1. Let stack be a new empty List.
+        // 2. Let indent be the empty String.
+        // 3. Let PropertyList be undefined.
+        let mut property_list = None;
+        // 4. Let ReplacerFunction be undefined.
+        let mut replacer_function = None;
+        // 5. If replacer is an Object, then
+        if let Ok(replacer) = Object::try_from(replacer) {
+            // a. If IsCallable(replacer) is true, then
+            if let Some(replacer) = is_callable(replacer, gc.nogc()) {
+                // i. Set ReplacerFunction to replacer.
+                replacer_function = Some(replacer.scope(agent, gc.nogc()));
+            } else if is_array(agent, replacer, gc.nogc())? {
+                // b. Else,
+                // i. Let isArray be ? IsArray(replacer).
+                // ii. If isArray is true, then
+                let replacer = replacer.scope(agent, gc.nogc());
+                // 1. Set PropertyList to a new empty List.
+                let mut list = vec![];
+                let mut seen = AHashSet::default();
+                // 2. Let len be ? LengthOfArrayLike(replacer).
+                let len = length_of_array_like(agent, replacer.get(agent), gc.reborrow())?;
+                // 3. Let k be 0.
+                // 4. Repeat, while k < len,
+                for k in 0..len {
+                    // a. Let prop be ! ToString(𝔽(k)).
+                    let prop = PropertyKey::from(SmallInteger::try_from(k).unwrap());
+                    // b. Let v be ? Get(replacer, prop).
+                    let v = get(agent, replacer.get(agent), prop, gc.reborrow())?;
+                    // c. Let item be undefined.
+                    // d. If v is a String, then
+                    let item = if let Ok(v) = String::try_from(v) {
+                        // i. Set item to v.
+                        v
+                    } else if v.is_number()
+                        || matches!(
+                            v,
+                            Value::PrimitiveObject(o)
+                                if o.is_number_object(agent) || o.is_string_object(agent)
+                        )
+                    {
+                        // e. Else if v is a Number, then
+                        // i. Set item to ! ToString(v).
+                        // f. Else if v is an Object, then
+                        // i. If v has a [[StringData]] or [[NumberData]]
+                        //    internal slot, set item to ? ToString(v).
+                        to_string(agent, v, gc.reborrow())?
+                    } else {
+                        continue;
+                    };
+                    // g. If item is not undefined and PropertyList does not
+                    //    contain item, then
+                    let item = item.as_str(agent).to_owned();
+                    if seen.insert(item.clone()) {
+                        // i. Append item to PropertyList.
+                        let item = PropertyKey::from_string(agent, item, gc.nogc());
+                        list.push(item.scope(agent, gc.nogc()));
+                    }
+                    // h. Set k to k + 1.
+                }
+                property_list = Some(list);
+            }
+        }
+
+        // 6. If space is an Object, then
+        let mut space = space.get(agent);
+        if let Value::PrimitiveObject(o) = space {
+            // a. If space has a [[NumberData]] internal slot, then
+            if o.is_number_object(agent) {
+                // i. Set space to ? ToNumber(space).
+                space = to_number(agent, space, gc.reborrow())?.into_value();
+            } else if o.is_string_object(agent) {
+                // b. Else if space has a [[StringData]] internal slot, then
+                // i. Set space to ? ToString(space).
+                space = to_string(agent, space, gc.reborrow())?.into_value();
+            }
+        }
+        let gap = if let Ok(space) = Number::try_from(space) {
+            // 7. If space is a Number, then
+            // a. Let spaceMV be ! ToIntegerOrInfinity(space).
+            let space_mv = to_integer_or_infinity_number(agent, space, gc.nogc());
+            // b. Set spaceMV to min(10, spaceMV).
+            // c. If spaceMV < 1, let gap be the empty String; otherwise let
+            //    gap be the String value containing spaceMV occurrences of the
+            //    code unit 0x0020 (SPACE).
+            " ".repeat(space_mv.into_i64().clamp(0, 10) as usize)
+        } else if let Ok(space) = String::try_from(space) {
+            // 8. Else if space is a String, then
+            // a. If the length of space ≤ 10, let gap be space; otherwise let
+            //    gap be the substring of space from 0 to 10.
+            let mut length = 0;
+            space
+                .as_str(agent)
+                .chars()
+                .take_while(|c| {
+                    length += c.len_utf16();
+                    length <= 10
+                })
+                .collect()
+        } else {
+            // 9. Else,
+            // a. Let gap be the empty String.
+            std::string::String::new()
+        };
+
+        // 10. Let wrapper be OrdinaryObjectCreate(%Object.prototype%).
+        let Object::Object(wrapper) =
+            ordinary_object_create_with_intrinsics(agent, Some(ProtoIntrinsics::Object), None)
+        else {
+            unreachable!()
+        };
+        // 11. Perform ! CreateDataPropertyOrThrow(wrapper, the empty String, value).
+        let empty_string = String::EMPTY_STRING.to_property_key();
+        unwrap_try(try_create_data_property_or_throw(
+            agent,
+            wrapper,
+            empty_string,
+            value.get(agent),
+            gc.nogc(),
+        ))
+        .unwrap();
+        // 12. Let state be the JSON Serialization Record { [[ReplacerFunction]]:
+        //     ReplacerFunction, [[Stack]]: stack, [[Indent]]: indent, [[Gap]]:
+        //     gap, [[PropertyList]]: PropertyList }.
+        let mut state = JSONSerializationRecord {
+            replacer_function,
+            stack: vec![],
+            indent: std::string::String::new(),
+            gap,
+            property_list,
+            result: std::string::String::new(),
+        };
+        // 13. Return ? SerializeJSONProperty(state, the empty String, wrapper).
+        let wrapper = wrapper.into_object().scope(agent, gc.nogc());
+        if serialize_json_property(
+            agent,
+            &mut state,
+            empty_string.scope_static(),
+            wrapper,
+            gc.reborrow(),
+        )? {
+            Ok(String::from_string(agent, state.result, gc.nogc()).into_value())
+        } else {
+            Ok(Value::Undefined)
+        }
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: RealmIdentifier) {
@@ -317,6 +484,397 @@ fn internalize_json_property<'a>(
     )
 }
 
+/// ### [25.5.2.1 JSON Serialization Record](https://tc39.es/ecma262/#sec-json-serialization-record)
+///
+/// A JSON Serialization Record is a Record value used to enable
+/// serialization to the JSON format.
+///
+/// Instead of returning the serialization of each value as a separate
+/// String, the serialization operations append to a shared result.
+struct JSONSerializationRecord<'a> {
+    /// ### \[\[ReplacerFunction]]
+    replacer_function: Option<Scoped<'a, Function<'static>>>,
+    /// ### \[\[PropertyList]]
+    property_list: Option<Vec<Scoped<'a, PropertyKey<'static>>>>,
+    /// ### \[\[Gap]]
+    gap: std::string::String,
+    /// ### \[\[Indent]]
+    indent: std::string::String,
+    /// ### \[\[Stack]]
+    stack: Vec<Scoped<'a, Object>>,
+    /// The serialized JSON text.
+    result: std::string::String,
+}
+
+/// ### [25.5.2.2 SerializeJSONProperty ( state, key, holder )](https://tc39.es/ecma262/#sec-serializejsonproperty)
+///
+/// The abstract operation SerializeJSONProperty takes arguments state (a
+/// JSON Serialization Record), key (a String), and holder (an Object) and
+/// returns either a normal completion containing either a String or
+/// undefined, or a throw completion.
+///
+/// Appends the String to the result of the state and returns true, or
+/// returns false without appending anything if the result is undefined.
+fn serialize_json_property<'a>(
+    agent: &mut Agent,
+    state: &mut JSONSerializationRecord<'a>,
+    key: Scoped<'a, PropertyKey<'static>>,
+    holder: Scoped<'a, Object>,
+    mut gc: GcScope<'_, 'a>,
+) -> JsResult<bool> {
+    // 1. Let value be ? Get(holder, key).
+    let mut value = get(agent, holder.get(agent), key.get(agent), gc.reborrow())?;
+    // 2. If value is an Object or value is a BigInt, then
+    if value.is_object() || value.is_bigint() {
+        // a. Let toJSON be ? GetV(value, "toJSON").
+        let scoped_value = value.scope(agent, gc.nogc());
+        let to_json = get_v(
+            agent,
+            value,
+            BUILTIN_STRING_MEMORY.toJSON.to_property_key(),
+            gc.reborrow(),
+        )?;
+        value = scoped_value.get(agent);
+        // b. If IsCallable(toJSON) is true, then
+        if let Some(to_json) = is_callable(to_json, gc.nogc()) {
+            // i. Set value to ? Call(toJSON, value, « key »).
+            let key = key.get(agent).convert_to_value(agent, gc.nogc());
+            value = call_function(
+                agent,
+                to_json.unbind(),
+                value,
+                Some(ArgumentsList(&[key])),
+                gc.reborrow(),
+            )?;
+        }
+    }
+    // 3. If state.[[ReplacerFunction]] is not undefined, then
+    if let Some(replacer_function) = &state.replacer_function {
+        // a. Set value to ? Call(state.[[ReplacerFunction]], holder, « key, value »).
+        let key = key.get(agent).convert_to_value(agent, gc.nogc());
+        value = call_function(
+            agent,
+            replacer_function.get(agent),
+            holder.get(agent).into_value(),
+            Some(ArgumentsList(&[key, value])),
+            gc.reborrow(),
+        )?;
+    }
+    // 4. If value is an Object, then
+    if let Value::PrimitiveObject(o) = value {
+        if o.is_number_object(agent) {
+            // a. If value has a [[NumberData]] internal slot, then
+            // i. Set value to ? ToNumber(value).
+            value = to_number(agent, value, gc.reborrow())?.into_value();
+        } else if o.is_string_object(agent) {
+            // b. Else if value has a [[StringData]] internal slot, then
+            // i. Set value to ? ToString(value).
+            value = to_string(agent, value, gc.reborrow())?.into_value();
+        } else if let PrimitiveObjectData::Boolean(boolean) = agent[o].data {
+            // c. Else if value has a [[BooleanData]] internal slot, then
+            // i. Set value to value.[[BooleanData]].
+            value = boolean.into();
+        } else if o.is_bigint_object(agent) {
+            // d. Else if value has a [[BigIntData]] internal slot, then
+            // i. Set value to value.[[BigIntData]].
+            value = BigInt::try_from(agent[o].data).unwrap().into_value();
+        }
+    }
+    // 5. If value is null, return "null".
+    if value.is_null() {
+        state.result.push_str("null");
+    } else if let Value::Boolean(value) = value {
+        // 6. If value is true, return "true".
+        // 7. If value is false, return "false".
+        state.result.push_str(if value { "true" } else { "false" });
+    } else if let Ok(value) = String::try_from(value) {
+        // 8. If value is a String, return QuoteJSONString(value).
+        quote_json_string(&mut state.result, value.as_str(agent));
+    } else if let Ok(value) = Number::try_from(value) {
+        // 9. If value is a Number, then
+        if value.is_finite(agent) {
+            // a. If value is finite, return ! ToString(value).
+            let value = Number::to_string_radix_10(agent, value, gc.nogc());
+            state.result.push_str(value.as_str(agent));
+        } else {
+            // b. Return "null".
+            state.result.push_str("null");
+        }
+    } else if value.is_bigint() {
+        // 10. If value is a BigInt, throw a TypeError exception.
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Cannot serialize a BigInt to JSON",
+            gc.nogc(),
+        ));
+    } else {
+        // 11. If value is an Object and IsCallable(value) is false, then
+        let Ok(value) = Object::try_from(value) else {
+            // 12. Return undefined.
+            return Ok(false);
+        };
+        if is_callable(value, gc.nogc()).is_some() {
+            return Ok(false);
+        }
+        // a. Let isArray be ? IsArray(value).
+        let is_array = is_array(agent, value, gc.nogc())?;
+        let value = value.scope(agent, gc.nogc());
+        if is_array {
+            // b. If isArray is true, return ? SerializeJSONArray(state, value).
+            serialize_json_array(agent, state, value, gc.reborrow())?;
+        } else {
+            // c. Return ? SerializeJSONObject(state, value).
+            serialize_json_object(agent, state, value, gc.reborrow())?;
+        }
+    }
+    Ok(true)
+}
+
+/// ### [25.5.2.3 QuoteJSONString ( value )](https://tc39.es/ecma262/#sec-quotejsonstring)
+///
+/// The abstract operation QuoteJSONString takes argument value (a String) and
+/// returns a String. It wraps value in 0x0022 (QUOTATION MARK) code units and
+/// escapes certain other code units within it. This operation interprets
+/// value as a sequence of UTF-16 encoded code points, as described in 6.1.4.
+///
+/// > Note: Strings are stored as UTF-8 and cannot contain lone surrogates, so
+/// > the surrogate escapes of step 2.b are never needed.
+fn quote_json_string(output: &mut std::string::String, value: &str) {
+    // 1. Let product be the String value consisting solely of the code unit
+    //    0x0022 (QUOTATION MARK).
+    output.push('"');
+    // 2. For each code point C of StringToCodePoints(value), do
+    for c in value.chars() {
+        match c {
+            // a. If C is listed in the “Code Point” column of Table 75, then
+            // i. Set product to the string-concatenation of product and the
+            //    escape sequence for C as specified in the “Escape Sequence”
+            //    column of the corresponding row.
+            '\u{8}' => output.push_str("\\b"),
+            '\t' => output.push_str("\\t"),
+            '\n' => output.push_str("\\n"),
+            '\u{c}' => output.push_str("\\f"),
+            '\r' => output.push_str("\\r"),
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            // b. Else if C has a numeric value less than 0x0020 (SPACE) or C
+            //    has the same numeric value as a leading surrogate or
+            //    trailing surrogate, then
+            // i. Let unit be the code unit whose numeric value is the numeric
+            //    value of C.
+            // ii. Set product to the string-concatenation of product and
+            //     UnicodeEscape(unit).
+            c if c < ' ' => write!(output, "\\u{:04x}", c as u32).unwrap(),
+            // c. Else,
+            // i. Set product to the string-concatenation of product and
+            //    UTF16EncodeCodePoint(C).
+            c => output.push(c),
+        }
+    }
+    // 3. Set product to the string-concatenation of product and the code unit
+    //    0x0022 (QUOTATION MARK).
+    output.push('"');
+    // 4. Return product.
+}
+
+/// Shared steps 1 and 2 of SerializeJSONObject and SerializeJSONArray.
+fn push_json_stack<'a>(
+    agent: &mut Agent,
+    state: &mut JSONSerializationRecord<'a>,
+    value: Scoped<'a, Object>,
+    gc: NoGcScope,
+) -> JsResult<()> {
+    // 1. If state.[[Stack]] contains value, throw a TypeError exception
+    //    because the structure is cyclical.
+    let object = value.get(agent);
+    if state.stack.iter().any(|entry| entry.get(agent) == object) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Cannot serialize a cyclic structure to JSON",
+            gc,
+        ));
+    }
+    // 2. Append value to state.[[Stack]].
+    state.stack.push(value);
+    Ok(())
+}
+
+/// ### [25.5.2.5 SerializeJSONObject ( state, value )](https://tc39.es/ecma262/#sec-serializejsonobject)
+///
+/// The abstract operation SerializeJSONObject takes arguments state (a JSON
+/// Serialization Record) and value (an Object) and returns either a normal
+/// completion containing a String or a throw completion. It serializes an
+/// object.
+fn serialize_json_object<'a>(
+    agent: &mut Agent,
+    state: &mut JSONSerializationRecord<'a>,
+    value: Scoped<'a, Object>,
+    mut gc: GcScope<'_, 'a>,
+) -> JsResult<()> {
+    // 1. If state.[[Stack]] contains value, throw a TypeError exception
+    //    because the structure is cyclical.
+    // 2. Append value to state.[[Stack]].
+    push_json_stack(agent, state, value.clone(), gc.nogc())?;
+    // 3. Let stepback be state.[[Indent]].
+    let stepback = state.indent.len();
+    // 4. Set state.[[Indent]] to the string-concatenation of state.[[Indent]]
+    //    and state.[[Gap]].
+    state.indent.push_str(&state.gap);
+    // 5. If state.[[PropertyList]] is not undefined, then
+    let k = if let Some(property_list) = &state.property_list {
+        // a. Let K be state.[[PropertyList]].
+        property_list.clone()
+    } else {
+        // 6. Else,
+        // a. Let K be ? EnumerableOwnProperties(value, key).
+        scoped_enumerable_own_keys(agent, value.clone(), gc.reborrow())?
+    };
+    // 7. Let partial be a new empty List.
+    state.result.push('{');
+    let mut partial_is_empty = true;
+    // 8. For each element P of K, do
+    for p in k {
+        // Note: The member is written out before its value is known, and
+        // removed again if the value turns out to be undefined.
+        let member_start = state.result.len();
+        if !partial_is_empty {
+            state.result.push(',');
+        }
+        if !state.gap.is_empty() {
+            state.result.push('\n');
+            state.result.push_str(&state.indent);
+        }
+        // b. If strP is not undefined, then
+        // i. Let member be QuoteJSONString(P).
+        let key = p.get(agent).as_display(agent).to_string();
+        quote_json_string(&mut state.result, &key);
+        // ii. Set member to the string-concatenation of member and ":".
+        state.result.push(':');
+        // iii. If state.[[Gap]] is not the empty String, then
+        if !state.gap.is_empty() {
+            // 1. Set member to the string-concatenation of member and the
+            //    code unit 0x0020 (SPACE).
+            state.result.push(' ');
+        }
+        // a. Let strP be ? SerializeJSONProperty(state, P, value).
+        // iv. Set member to the string-concatenation of member and strP.
+        // v. Append member to partial.
+        if serialize_json_property(agent, state, p, value.clone(), gc.reborrow())? {
+            partial_is_empty = false;
+        } else {
+            state.result.truncate(member_start);
+        }
+    }
+    // 11. Remove the last element of state.[[Stack]].
+    state.stack.pop();
+    // 12. Set state.[[Indent]] to stepback.
+    state.indent.truncate(stepback);
+    // 9. If partial is empty, then
+    // a. Let final be "{}".
+    // 10. Else,
+    // a. If state.[[Gap]] is the empty String, then
+    // i. Let properties be the String value formed by concatenating all the
+    //    element Strings of partial with each adjacent pair of Strings
+    //    separated with the code unit 0x002C (COMMA). A comma is not inserted
+    //    either before the first String or after the last String.
+    // ii. Let final be the string-concatenation of "{", properties, and "}".
+    // b. Else,
+    // i. Let separator be the string-concatenation of the code unit 0x002C
+    //    (COMMA), the code unit 0x000A (LINE FEED), and state.[[Indent]].
+    // ii. Let properties be the String value formed by concatenating all the
+    //     element Strings of partial with each adjacent pair of Strings
+    //     separated with separator. The separator String is not inserted
+    //     either before the first String or after the last String.
+    // iii. Let final be the string-concatenation of "{", the code unit 0x000A
+    //      (LINE FEED), state.[[Indent]], properties, the code unit 0x000A
+    //      (LINE FEED), stepback, and "}".
+    if !partial_is_empty && !state.gap.is_empty() {
+        state.result.push('\n');
+        state.result.push_str(&state.indent);
+    }
+    state.result.push('}');
+    // 13. Return final.
+    Ok(())
+}
+
+/// ### [25.5.2.6 SerializeJSONArray ( state, value )](https://tc39.es/ecma262/#sec-serializejsonarray)
+///
+/// The abstract operation SerializeJSONArray takes arguments state (a JSON
+/// Serialization Record) and value (an ECMAScript language value) and
+/// returns either a normal completion containing a String or a throw
+/// completion. It serializes an array.
+fn serialize_json_array<'a>(
+    agent: &mut Agent,
+    state: &mut JSONSerializationRecord<'a>,
+    value: Scoped<'a, Object>,
+    mut gc: GcScope<'_, 'a>,
+) -> JsResult<()> {
+    // 1. If state.[[Stack]] contains value, throw a TypeError exception
+    //    because the structure is cyclical.
+    // 2. Append value to state.[[Stack]].
+    push_json_stack(agent, state, value.clone(), gc.nogc())?;
+    // 3. Let stepback be state.[[Indent]].
+    let stepback = state.indent.len();
+    // 4. Set state.[[Indent]] to the string-concatenation of state.[[Indent]]
+    //    and state.[[Gap]].
+    state.indent.push_str(&state.gap);
+    // 5. Let partial be a new empty List.
+    state.result.push('[');
+    // 6. Let len be ? LengthOfArrayLike(value).
+    let len = length_of_array_like(agent, value.get(agent), gc.reborrow())?;
+    // 7. Let index be 0.
+    // 8. Repeat, while index < len,
+    for index in 0..len {
+        if index > 0 {
+            state.result.push(',');
+        }
+        if !state.gap.is_empty() {
+            state.result.push('\n');
+            state.result.push_str(&state.indent);
+        }
+        // a. Let strP be ? SerializeJSONProperty(state, ! ToString(𝔽(index)), value).
+        let key = PropertyKey::from(SmallInteger::try_from(index).unwrap()).scope_static();
+        // b. If strP is undefined, then
+        if !serialize_json_property(agent, state, key, value.clone(), gc.reborrow())? {
+            // i. Append "null" to partial.
+            state.result.push_str("null");
+        }
+        // c. Else,
+        // i. Append strP to partial.
+        // d. Set index to index + 1.
+    }
+    // 11. Remove the last element of state.[[Stack]].
+    state.stack.pop();
+    // 12. Set state.[[Indent]] to stepback.
+    state.indent.truncate(stepback);
+    // 9. If partial is empty, then
+    // a. Let final be "[]".
+    // 10. Else,
+    // a. If state.[[Gap]] is the empty String, then
+    // i. Let properties be the String value formed by concatenating all the
+    //    element Strings of partial with each adjacent pair of Strings
+    //    separated with the code unit 0x002C (COMMA). A comma is not inserted
+    //    either before the first String or after the last String.
+    // ii. Let final be the string-concatenation of "[", properties, and "]".
+    // b. Else,
+    // i. Let separator be the string-concatenation of the code unit 0x002C
+    //    (COMMA), the code unit 0x000A (LINE FEED), and state.[[Indent]].
+    // ii. Let properties be the String value formed by concatenating all the
+    //     element Strings of partial with each adjacent pair of Strings
+    //     separated with separator. The separator String is not inserted
+    //     either before the first String or after the last String.
+    // iii. Let final be the string-concatenation of "[", the code unit 0x000A
+    //      (LINE FEED), state.[[Indent]], properties, the code unit 0x000A
+    //      (LINE FEED), stepback, and "]".
+    if len > 0 && !state.gap.is_empty() {
+        state.result.push('\n');
+        state.result.push_str(&state.indent);
+    }
+    state.result.push(']');
+    // 13. Return final.
+    Ok(())
+}
+
 pub(crate) fn value_from_json(
     agent: &mut Agent,
     json: &sonic_rs::Value,
@@ -297,8 +297,8 @@ impl StringPrototype {
             Ok(Value::Undefined)
         } else {
             // 8. Return the substring of S from k to k + 1.
-            let ch = s.utf16_char(agent, usize::try_from(k).unwrap());
-            Ok(SmallString::from_code_point(ch).into_value())
+            let code_unit = s.char_code_at(agent, usize::try_from(k).unwrap());
+            Ok(String::from_code_unit(code_unit).into_value())
         }
     }
 
@@ -331,8 +331,8 @@ impl StringPrototype {
             Ok(String::EMPTY_STRING.into_value())
         } else {
             // 6. Return the substring of S from position to position + 1.
-            let ch = s.utf16_char(agent, usize::try_from(position).unwrap());
-            Ok(SmallString::from_code_point(ch).into_value())
+            let code_unit = s.char_code_at(agent, usize::try_from(position).unwrap());
+            Ok(String::from_code_unit(code_unit).into_value())
         }
     }
 
@@ -366,8 +366,8 @@ impl StringPrototype {
         } else {
             // 6. Return the Number value for the numeric value of the code unit at index position
             // within the String S.
-            let ch = s.utf16_char(agent, usize::try_from(position).unwrap());
-            Ok(Value::from(ch as u32))
+            let code_unit = s.char_code_at(agent, usize::try_from(position).unwrap());
+            Ok(Value::from(code_unit))
         }
     }
 
@@ -400,13 +400,9 @@ impl StringPrototype {
             Ok(Value::Undefined)
         } else {
             // 6. Let cp be CodePointAt(S, position).
+            let cp = s.code_point_at(agent, usize::try_from(position).unwrap());
             // 7. Return 𝔽(cp.[[CodePoint]]).
-            // TODO: Deal with lone surrogates.
-            let u8_idx = s
-                .utf8_index(agent, usize::try_from(position).unwrap())
-                .unwrap();
-            let ch = s.as_str(agent)[u8_idx..].chars().next().unwrap();
-            Ok(Value::from(ch as u32))
+            Ok(Value::from(cp))
         }
     }
 
@@ -890,6 +886,10 @@ impl StringPrototype {
         };
 
         // 6. Let ns be the String value that is the result of normalizing S into the normalization form named by f as specified in the latest Unicode Standard, Normalization Forms.
+        // NOTE: Strings cannot contain lone surrogates yet. Once they can,
+        // they should be passed through unchanged: A lone surrogate is a
+        // starter with no decomposition, so only the well-formed runs
+        // between them need normalizing.
         match unicode_normalize(s.as_str(agent), f) {
             // 7. Return ns.
             None => Ok(s.into_value()),
@@ -985,6 +985,13 @@ impl StringPrototype {
             return Ok(s.into_value());
         }
 
+        // Short results can be built without allocating.
+        if let String::SmallString(s) = s {
+            if let Some(result) = s.try_repeat(n as usize) {
+                return Ok(String::SmallString(result).into_value());
+            }
+        }
+
         // 6. Return the String value that is made from n copies of S appended together.
         Ok(Value::from_string(
             agent,
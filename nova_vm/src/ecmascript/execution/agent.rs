@@ -20,7 +20,15 @@ use crate::{
         types::{Function, IntoValue, Object, Reference, String, Symbol, Value},
     }, engine::{context::{GcScope, NoGcScope}, rootable::HeapRootData, TryResult, Vm}, heap::{heap_gc::heap_gc, CreateHeapData, PrimitiveHeapIndexable}, Heap
 };
-use std::{any::Any, cell::RefCell, ptr::NonNull};
+use std::{
+    any::Any,
+    cell::RefCell,
+    ptr::NonNull,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 #[derive(Debug, Default)]
 pub struct Options {
@@ -45,6 +53,21 @@ impl JsError {
     pub fn to_string<'gc>(self, agent: &mut Agent, gc: GcScope<'gc, '_>) -> String<'gc> {
         to_string(agent, self.0, gc).unwrap()
     }
+
+    /// Returns true if this is the error thrown to unwind a script that was
+    /// interrupted through an [`InterruptHandle`]. Errors thrown by scripts
+    /// never compare equal to it, even if they carry the same message.
+    pub fn is_interrupt(self, agent: &Agent) -> bool {
+        agent.interrupt_error == Some(self.0)
+    }
+
+    /// Formats the thrown value for display, e.g. when reporting an uncaught
+    /// exception. Errors format as `name: message` through their `toString`
+    /// method. This never throws: a value whose string conversion throws
+    /// formats as "Object".
+    pub fn format(self, agent: &mut Agent, gc: GcScope) -> std::string::String {
+        self.0.string_repr(agent, gc).as_str(agent).to_owned()
+    }
 }
 
 // #[derive(Debug)]
@@ -130,6 +153,37 @@ pub trait HostHooks: std::fmt::Debug {
     }
 }
 
+/// Handle for interrupting scripts running in an Agent, e.g. to enforce a
+/// timeout on untrusted code.
+///
+/// The handle can be cloned and sent to other threads. Once an interrupt is
+/// requested, the running script throws an error at the next loop iteration
+/// or function call; [`JsError::is_interrupt`] tells it apart from errors
+/// thrown by scripts. The request stays in effect until it is cleared: while
+/// it is in effect, `catch` blocks are skipped so that the
+/// error unwinds all the way to the embedder, and any further script
+/// execution is immediately interrupted as well. Clear the request with
+/// [`GcAgent::clear_interrupt`] before running scripts on the Agent again.
+#[derive(Debug, Default, Clone)]
+pub struct InterruptHandle(Arc<AtomicBool>);
+
+impl InterruptHandle {
+    /// Requests that the running script be interrupted.
+    pub fn request_interrupt(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if an interrupt has been requested and not yet cleared.
+    pub fn is_interrupt_requested(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Clears an interrupt request, allowing scripts to run again.
+    pub fn clear_interrupt(&self) {
+        self.0.store(false, Ordering::Relaxed);
+    }
+}
+
 /// Owned ECMAScript Agent that can be used to run code but also to run garbage
 /// collection on the Agent heap.
 pub struct GcAgent {
@@ -239,6 +293,26 @@ impl GcAgent {
         result
     }
 
+    /// Returns a handle for interrupting scripts running in this Agent from
+    /// another thread.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.agent.interrupt_handle.clone()
+    }
+
+    /// Requests that the running script be interrupted. See
+    /// [`InterruptHandle`].
+    pub fn request_interrupt(&self) {
+        self.agent.interrupt_handle.request_interrupt();
+    }
+
+    /// Clears an interrupt request, allowing scripts to run again. The
+    /// request is not cleared automatically once the interrupted script has
+    /// unwound.
+    pub fn clear_interrupt(&mut self) {
+        self.agent.interrupt_handle.clear_interrupt();
+        self.agent.interrupt_error = None;
+    }
+
     pub fn gc(&mut self) {
         if self.agent.options.disable_gc {
             // GC is disabled; no-op
@@ -266,6 +340,9 @@ pub struct Agent {
     pub(crate) stack_refs: RefCell<Vec<HeapRootData>>,
     /// Temporary storage for on-stack VMs.
     pub(crate) vm_stack: Vec<NonNull<Vm>>,
+    pub(crate) interrupt_handle: InterruptHandle,
+    /// The error most recently thrown to unwind an interrupted script.
+    pub(crate) interrupt_error: Option<Value>,
 }
 
 impl Agent {
@@ -279,9 +356,27 @@ impl Agent {
             execution_context_stack: Vec::new(),
             stack_refs: RefCell::new(Vec::with_capacity(64)),
             vm_stack: Vec::with_capacity(16),
+            interrupt_handle: InterruptHandle::default(),
+            interrupt_error: None,
         }
     }
 
+    /// Returns true if the running script should be interrupted.
+    pub(crate) fn is_interrupt_requested(&self) -> bool {
+        self.interrupt_handle.is_interrupt_requested()
+    }
+
+    /// Throws the error used to unwind an interrupted script.
+    pub(crate) fn throw_interrupt(&mut self, gc: NoGcScope) -> JsError {
+        let error = self.throw_exception_with_static_message(
+            ExceptionType::Error,
+            "Script execution was interrupted",
+            gc,
+        );
+        self.interrupt_error = Some(error.value());
+        error
+    }
+
     fn get_created_realm_root(&mut self) -> RealmIdentifier {
         assert!(!self.execution_context_stack.is_empty());
         let identifier = self.current_realm_id();
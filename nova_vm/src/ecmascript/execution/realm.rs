@@ -165,6 +165,11 @@ pub struct Realm {
 unsafe impl Send for Realm {}
 
 impl Realm {
+    /// Get the global object of this realm.
+    pub fn global_object(&self) -> Object {
+        self.global_object
+    }
+
     pub(crate) fn intrinsics(&self) -> &Intrinsics {
         &self.intrinsics
     }
@@ -24,7 +24,7 @@ use crate::{
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 use ahash::AHashSet;
-use oxc_ast::ast::{BindingIdentifier, Program, VariableDeclarationKind};
+use oxc_ast::ast::{BindingIdentifier, Program, Statement, VariableDeclarationKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_ecmascript::BoundNames;
 use oxc_span::SourceType;
@@ -243,6 +243,34 @@ pub fn parse_script(
     // }
 }
 
+/// The normal completion of a script evaluation.
+///
+/// ScriptEvaluation replaces an empty completion value with undefined. This
+/// keeps the knowledge of whether the script produced a value at all, which
+/// REPLs can use to decide whether to print the result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScriptCompletion {
+    /// The completion value of the script; undefined if the completion was
+    /// empty.
+    pub value: Value,
+    /// True if the script consisted only of declarations and empty
+    /// statements and thus had an empty completion value.
+    pub is_empty: bool,
+}
+
+/// Returns true if the completion value of the statements is always empty.
+fn statements_have_empty_completion(statements: &[Statement]) -> bool {
+    statements.iter().all(|statement| match statement {
+        Statement::BlockStatement(block) => statements_have_empty_completion(&block.body),
+        Statement::EmptyStatement(_)
+        | Statement::DebuggerStatement(_)
+        | Statement::VariableDeclaration(_)
+        | Statement::FunctionDeclaration(_)
+        | Statement::ClassDeclaration(_) => true,
+        _ => false,
+    })
+}
+
 /// ### [16.1.6 ScriptEvaluation ( scriptRecord )](https://tc39.es/ecma262/#sec-runtime-semantics-scriptevaluation)
 ///
 /// The abstract operation ScriptEvaluation takes argument scriptRecord (a
@@ -251,8 +279,21 @@ pub fn parse_script(
 pub fn script_evaluation(
     agent: &mut Agent,
     script: Script,
-    mut gc: GcScope<'_, '_>,
+    gc: GcScope<'_, '_>,
 ) -> JsResult<Value> {
+    script_evaluation_with_completion(agent, script, gc).map(|completion| completion.value)
+}
+
+/// ### [16.1.6 ScriptEvaluation ( scriptRecord )](https://tc39.es/ecma262/#sec-runtime-semantics-scriptevaluation)
+///
+/// Performs ScriptEvaluation but returns a [`ScriptCompletion`] which also
+/// tells whether the completion value of the script was empty.
+pub fn script_evaluation_with_completion(
+    agent: &mut Agent,
+    script: Script,
+    mut gc: GcScope<'_, '_>,
+) -> JsResult<ScriptCompletion> {
+    let is_empty = statements_have_empty_completion(&script.ecmascript_code.body);
     let realm_id = script.realm;
     let is_strict_mode = script.ecmascript_code.is_strict();
     let source_code = script.source_code;
@@ -302,16 +343,25 @@ pub fn script_evaluation(
         global_declaration_instantiation(agent, script, global_env.unwrap(), gc.reborrow());
 
     // 13. If result.[[Type]] is normal, then
-    let result: JsResult<Value> = if result.is_ok() {
+    let result: JsResult<ScriptCompletion> = if result.is_ok() {
         let bytecode = Executable::compile_script(agent, script, gc.nogc());
         // a. Set result to Completion(Evaluation of script).
-        // b. If result.[[Type]] is normal and result.[[Value]] is empty, then
-        // i. Set result to NormalCompletion(undefined).
         let result = Vm::execute(agent, bytecode, None, gc).into_js_result();
         // SAFETY: The bytecode is not accessible by anyone and no one will try
         // to re-run it.
         unsafe { bytecode.try_drop(agent) };
-        result
+        // b. If result.[[Type]] is normal and result.[[Value]] is empty, then
+        result.map(|value| {
+            if is_empty {
+                // i. Set result to NormalCompletion(undefined).
+                ScriptCompletion {
+                    value: Value::Undefined,
+                    is_empty,
+                }
+            } else {
+                ScriptCompletion { value, is_empty }
+            }
+        })
     } else {
         Err(result.err().unwrap())
     };
@@ -581,16 +631,25 @@ mod test {
     use crate::engine::unwrap_try;
     use crate::{
         ecmascript::{
-            abstract_operations::operations_on_objects::create_data_property_or_throw,
+            abstract_operations::operations_on_objects::{
+                call_function, create_data_property_or_throw, get,
+            },
             builders::builtin_function_builder::BuiltinFunctionBuilder,
             builtins::{ArgumentsList, Behaviour, Builtin},
             execution::{
                 agent::Options, create_realm, initialize_default_realm, set_realm_global_object,
                 Agent, DefaultHostHooks, ExecutionContext,
             },
-            scripts_and_modules::script::{parse_script, script_evaluation},
-            types::{InternalMethods, IntoValue, Number, Object, PropertyKey, String, Value},
+            scripts_and_modules::script::{
+                parse_script, script_evaluation, script_evaluation_with_completion,
+                ScriptCompletion,
+            },
+            types::{
+                Function, InternalMethods, IntoValue, Number, Object, PropertyKey, String, Value,
+                BUILTIN_STRING_MEMORY,
+            },
         },
+        heap::heap_gc::heap_gc,
         SmallInteger,
     };
 
@@ -911,10 +970,10 @@ mod test {
             .unwrap();
         assert!(foo.is_object());
         let result = Object::try_from(foo).unwrap();
-        assert!(result
-            .internal_own_property_keys(&mut agent, gc)
-            .unwrap()
-            .is_empty());
+        assert_eq!(
+            result.internal_own_property_keys(&mut agent, gc).unwrap(),
+            [PropertyKey::from(BUILTIN_STRING_MEMORY.length)]
+        );
     }
 
     #[test]
@@ -975,7 +1034,14 @@ mod test {
         let source_text = String::from_static_str(&mut agent, "function foo() {}", gc.nogc());
         let script = parse_script(&mut agent, source_text, realm, false, None, gc.nogc()).unwrap();
         let result = script_evaluation(&mut agent, script, gc.reborrow()).unwrap();
-        assert!(result.is_function());
+        assert_eq!(result, Value::Undefined);
+
+        let foo_key = String::from_static_str(&mut agent, "foo", gc.nogc());
+        let global_env = agent.get_realm(realm).global_env.unwrap();
+        assert!(global_env
+            .get_binding_value(&mut agent, foo_key.unbind(), true, gc)
+            .unwrap()
+            .is_function());
     }
 
     #[test]
@@ -1093,6 +1159,179 @@ mod test {
         assert_eq!(result, Value::Null);
     }
 
+    #[test]
+    fn sort_with_garbage_collecting_comparator() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let realm = agent.current_realm_id();
+
+        struct GcComparator;
+
+        impl Builtin for GcComparator {
+            const NAME: String<'static> = String::from_small_string("compare");
+
+            const LENGTH: u8 = 2;
+
+            const BEHAVIOUR: Behaviour = Behaviour::Regular(
+                |agent: &mut Agent,
+                 _: Value,
+                 arguments_list: ArgumentsList,
+                 gc: GcScope<'_, '_>| {
+                    let a = String::try_from(arguments_list.get(0)).unwrap();
+                    let b = String::try_from(arguments_list.get(1)).unwrap();
+                    let ordering = a.as_str(agent).cmp(b.as_str(agent)) as i32;
+                    // Collect garbage on every comparison: The items being
+                    // sorted are only kept alive and up to date by sort
+                    // itself.
+                    let mut root_realms = [Some(agent.current_realm_id())];
+                    heap_gc(agent, &mut root_realms, gc);
+                    Ok(Value::from(ordering))
+                },
+            );
+        }
+
+        let comparator = BuiltinFunctionBuilder::new::<GcComparator>(&mut agent, realm).build();
+
+        // Interleave garbage with the items so that collecting it moves the
+        // items in the heap.
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var garbage = [];
+            var items = [];
+            for (let i = 0; i < 64; i++) {
+                garbage.push('garbage string number ' + i);
+                items.push('heap string item ' + ((i * 37) % 64));
+            }
+            garbage = undefined;
+            items",
+            gc.nogc(),
+        );
+        let script = parse_script(&mut agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        let items = script_evaluation(&mut agent, script, gc.reborrow())
+            .unwrap()
+            .unbind();
+        let items = Object::try_from(items).unwrap();
+        let sort_key = PropertyKey::from_static_str(&mut agent, "sort", gc.nogc()).unbind();
+        let sort = get(&mut agent, items, sort_key, gc.reborrow())
+            .unwrap()
+            .unbind();
+        call_function(
+            &mut agent,
+            Function::try_from(sort).unwrap(),
+            items.into_value(),
+            Some(ArgumentsList(&[comparator.into_value()])),
+            gc.reborrow(),
+        )
+        .unwrap();
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "items.length === 64 &&
+                new Set(items).size === 64 &&
+                items.every((item, i) => i === 0 || items[i - 1] < item) &&
+                items.every((item) => item.startsWith('heap string item '))",
+            gc.nogc(),
+        );
+        let script = parse_script(&mut agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        let result = script_evaluation(&mut agent, script, gc.reborrow()).unwrap();
+        assert_eq!(result, Value::Boolean(true));
+    }
+
+    #[test]
+    fn date_setters_with_garbage_collecting_value_of() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let realm = agent.current_realm_id();
+
+        struct GcValueOf;
+
+        impl Builtin for GcValueOf {
+            const NAME: String<'static> = String::from_small_string("valueOf");
+
+            const LENGTH: u8 = 0;
+
+            const BEHAVIOUR: Behaviour = Behaviour::Regular(
+                |agent: &mut Agent, _: Value, _: ArgumentsList, gc: GcScope<'_, '_>| {
+                    // Collect garbage during the argument conversion: The
+                    // Date being set is only kept alive and up to date by
+                    // the setter itself.
+                    let mut root_realms = [Some(agent.current_realm_id())];
+                    heap_gc(agent, &mut root_realms, gc);
+                    Ok(Value::from(30))
+                },
+            );
+        }
+
+        // Allocate garbage Dates before the Date being set so that collecting
+        // them moves it in the heap.
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var garbage = [];
+            for (let i = 0; i < 8; i++) {
+                garbage.push(new Date(i));
+            }
+            var date = new Date(0);
+            garbage = undefined;
+            var makeValue = (valueOf) => ({ valueOf });",
+            gc.nogc(),
+        );
+        let script = parse_script(&mut agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        script_evaluation(&mut agent, script, gc.reborrow()).unwrap();
+
+        for setter in ["setTime", "setMinutes"] {
+            // Nothing is kept alive across iterations: each collection may
+            // move any value that isn't reachable from the realm.
+            let value_of = BuiltinFunctionBuilder::new::<GcValueOf>(&mut agent, realm).build();
+            let source_text = String::from_static_str(&mut agent, "makeValue", gc.nogc());
+            let script =
+                parse_script(&mut agent, source_text, realm, false, None, gc.nogc()).unwrap();
+            let make_value = script_evaluation(&mut agent, script, gc.reborrow())
+                .unwrap()
+                .unbind();
+            let value = call_function(
+                &mut agent,
+                Function::try_from(make_value).unwrap(),
+                Value::Undefined,
+                Some(ArgumentsList(&[value_of.into_value()])),
+                gc.reborrow(),
+            )
+            .unwrap()
+            .unbind();
+            let source_text = String::from_static_str(&mut agent, "date", gc.nogc());
+            let script =
+                parse_script(&mut agent, source_text, realm, false, None, gc.nogc()).unwrap();
+            let date = script_evaluation(&mut agent, script, gc.reborrow())
+                .unwrap()
+                .unbind();
+            let date = Object::try_from(date).unwrap();
+            let setter_key = PropertyKey::from_str(&mut agent, setter, gc.nogc()).unbind();
+            let setter = get(&mut agent, date, setter_key, gc.reborrow())
+                .unwrap()
+                .unbind();
+            call_function(
+                &mut agent,
+                Function::try_from(setter).unwrap(),
+                date.into_value(),
+                Some(ArgumentsList(&[value])),
+                gc.reborrow(),
+            )
+            .unwrap();
+        }
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "date.getTime() === 30 * 60 * 1000 + 30",
+            gc.nogc(),
+        );
+        let script = parse_script(&mut agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        let result = script_evaluation(&mut agent, script, gc.reborrow()).unwrap();
+        assert_eq!(result, Value::Boolean(true));
+    }
+
     #[test]
     fn if_statement() {
         let (mut gc, mut scope) = unsafe { GcScope::create_root() };
@@ -1213,6 +1452,32 @@ mod test {
         assert_eq!(i, Value::from(3));
     }
 
+    #[test]
+    fn for_loop_without_test() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        let realm = create_realm(&mut agent, gc.nogc());
+        set_realm_global_object(&mut agent, realm, None, None);
+        let source_text = String::from_static_str(
+            &mut agent,
+            "var i = 0; for (;;) { i++; if (i === 3) break; }",
+            gc.nogc(),
+        );
+        let script = parse_script(&mut agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        script_evaluation(&mut agent, script, gc.reborrow()).unwrap();
+        let key = PropertyKey::from_static_str(&mut agent, "i", gc.nogc()).unbind();
+        let i: Value = agent
+            .get_realm(realm)
+            .global_object
+            .internal_get_own_property(&mut agent, key, gc)
+            .unwrap()
+            .unwrap()
+            .value
+            .unwrap();
+        assert_eq!(i, Value::from(3));
+    }
+
     #[test]
     fn lexical_declarations() {
         let (mut gc, mut scope) = unsafe { GcScope::create_root() };
@@ -1765,4 +2030,62 @@ mod test {
         let result = script_evaluation(&mut agent, script, gc.reborrow()).unwrap();
         assert_eq!(result, Value::Undefined);
     }
+
+    #[test]
+    fn completion_of_expression_is_not_empty() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let realm = agent.current_realm_id();
+
+        let source_text = String::from_static_str(&mut agent, "1 + 1", gc.nogc());
+        let script = parse_script(&mut agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        let result = script_evaluation_with_completion(&mut agent, script, gc.reborrow()).unwrap();
+        assert_eq!(
+            result,
+            ScriptCompletion {
+                value: 2.into(),
+                is_empty: false
+            }
+        );
+    }
+
+    #[test]
+    fn completion_of_declarations_is_empty() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let realm = agent.current_realm_id();
+
+        let source_text = String::from_static_str(
+            &mut agent,
+            "let x = 1; function foo() {} { const y = 2; }",
+            gc.nogc(),
+        );
+        let script = parse_script(&mut agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        let result = script_evaluation_with_completion(&mut agent, script, gc.reborrow()).unwrap();
+        assert_eq!(
+            result,
+            ScriptCompletion {
+                value: Value::Undefined,
+                is_empty: true
+            }
+        );
+    }
+
+    #[test]
+    fn completion_of_throwing_script_is_exception() {
+        let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+        let mut gc = GcScope::new(&mut gc, &mut scope);
+        let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+        initialize_default_realm(&mut agent, gc.reborrow());
+        let realm = agent.current_realm_id();
+
+        let source_text = String::from_static_str(&mut agent, "let x = 1; throw 3;", gc.nogc());
+        let script = parse_script(&mut agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        let result = script_evaluation_with_completion(&mut agent, script, gc.reborrow());
+        assert_eq!(result.unwrap_err().value(), 3.into());
+    }
 }
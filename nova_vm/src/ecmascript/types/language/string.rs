@@ -292,6 +292,15 @@ impl<'a> String<'a> {
         String::SmallString(SmallString::from_str_unchecked(message))
     }
 
+    /// Create a String containing a single UTF-16 code unit.
+    ///
+    /// NOTE: Strings cannot yet hold lone surrogates, so a surrogate code unit
+    /// is replaced with U+FFFD REPLACEMENT CHARACTER.
+    pub fn from_code_unit(code_unit: u16) -> String<'static> {
+        let ch = char::from_u32(code_unit.into()).unwrap_or(char::REPLACEMENT_CHARACTER);
+        String::SmallString(SmallString::from_code_point(ch))
+    }
+
     pub fn concat<'gc>(
         agent: &mut Agent,
         strings: impl AsRef<[Self]>,
@@ -403,6 +412,33 @@ impl<'a> String<'a> {
         }
     }
 
+    /// Returns the UTF-16 code unit at the given UTF-16 index.
+    pub fn char_code_at(
+        self,
+        agent: &impl Index<HeapString<'static>, Output = StringHeapData>,
+        idx: usize,
+    ) -> u16 {
+        match self {
+            String::String(s) => agent[s.unbind()].char_code_at(idx),
+            String::SmallString(s) => s.char_code_at(idx),
+        }
+    }
+
+    /// ### [11.1.4 Static Semantics: CodePointAt ( string, position )](https://tc39.es/ecma262/#sec-codepointat)
+    ///
+    /// Returns the code point starting at the given UTF-16 index, or the code
+    /// unit if the index is the second code unit of a surrogate pair.
+    pub fn code_point_at(
+        self,
+        agent: &impl Index<HeapString<'static>, Output = StringHeapData>,
+        idx: usize,
+    ) -> u32 {
+        match self {
+            String::String(s) => agent[s.unbind()].code_point_at(idx),
+            String::SmallString(s) => s.code_point_at(idx),
+        }
+    }
+
     /// Returns the corresponding UTF-8 index for a UTF-16 index into the
     /// string, or `None` if the UTF-16 index is the second code unit in a
     /// surrogate pair.
@@ -489,9 +525,9 @@ impl<'a> String<'a> {
         } else if let PropertyKey::Integer(index) = property_key {
             let index = index.into_i64();
             if index >= 0 && (index as usize) < self.utf16_len(agent) {
-                let ch = self.utf16_char(agent, index as usize);
+                let code_unit = self.char_code_at(agent, index as usize);
                 Some(PropertyDescriptor {
-                    value: Some(SmallString::from_code_point(ch).into_value()),
+                    value: Some(String::from_code_unit(code_unit).into_value()),
                     writable: Some(false),
                     get: None,
                     set: None,
@@ -124,6 +124,28 @@ impl StringHeapData {
         ch
     }
 
+    /// Returns the UTF-16 code unit at the given UTF-16 index.
+    pub fn char_code_at(&self, idx: usize) -> u16 {
+        // The second code unit of a surrogate pair has no UTF-8 index of its
+        // own; it is found through the first one.
+        let (utf8_idx, unit) = match self.utf8_index(idx) {
+            Some(utf8_idx) => (utf8_idx, 0),
+            None => (self.utf8_index(idx - 1).unwrap(), 1),
+        };
+        let ch = self.as_str()[utf8_idx..].chars().next().unwrap();
+        let mut buf = [0; 2];
+        ch.encode_utf16(&mut buf)[unit]
+    }
+
+    /// Returns the code point starting at the given UTF-16 index, or the code
+    /// unit if the index is the second code unit of a surrogate pair.
+    pub fn code_point_at(&self, idx: usize) -> u32 {
+        match self.utf8_index(idx) {
+            Some(utf8_idx) => self.as_str()[utf8_idx..].chars().next().unwrap() as u32,
+            None => self.char_code_at(idx) as u32,
+        }
+    }
+
     pub fn utf8_index(&self, utf16_idx: usize) -> Option<usize> {
         if utf16_idx == 0 {
             Some(0)
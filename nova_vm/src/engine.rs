@@ -12,7 +12,7 @@ pub mod small_integer;
 use std::ops::ControlFlow;
 
 pub(crate) use bytecode::*;
-pub use rootable::{Global, Scoped};
+pub use rootable::{Global, Scoped, WeakGlobal};
 
 /// Result of methods that are not allowed to call JavaScript or perform
 /// garbage collection.
@@ -2600,14 +2600,16 @@ impl CompileEvaluation for ast::ForStatement<'_> {
         }
 
         let loop_jump = ctx.get_jump_index_to_here();
-        if let Some(test) = &self.test {
+        // Note: A missing test is always true, so there is nothing to jump
+        // over the body with.
+        let end_jump = self.test.as_ref().map(|test| {
             test.compile(ctx);
             if is_reference(test) {
                 ctx.add_instruction(Instruction::GetValue);
             }
-        }
-        // jump over consequent if test fails
-        let end_jump = ctx.add_instruction_with_jump_slot(Instruction::JumpIfNot);
+            // jump over consequent if test fails
+            ctx.add_instruction_with_jump_slot(Instruction::JumpIfNot)
+        });
 
         self.body.compile(ctx);
 
@@ -2624,7 +2626,9 @@ impl CompileEvaluation for ast::ForStatement<'_> {
             update.compile(ctx);
         }
         ctx.add_jump_instruction_to_index(Instruction::Jump, loop_jump);
-        ctx.set_jump_target_here(end_jump);
+        if let Some(end_jump) = end_jump {
+            ctx.set_jump_target_here(end_jump);
+        }
 
         let own_breaks = ctx.current_break.take().unwrap();
         for break_entry in own_breaks {
@@ -284,6 +284,11 @@ impl<'a> Vm {
         executable: Executable,
         mut gc: GcScope<'a, '_>,
     ) -> ExecutionResult {
+        // Each function call executes in a new VM: check for interrupts so
+        // that unbounded recursion can be stopped.
+        if agent.is_interrupt_requested() {
+            return ExecutionResult::Throw(agent.throw_interrupt(gc.nogc()));
+        }
         #[cfg(feature = "interleaved-gc")]
         let do_gc = !agent.options.disable_gc;
         #[cfg(feature = "interleaved-gc")]
@@ -345,6 +350,10 @@ impl<'a> Vm {
 
     #[must_use]
     fn handle_error(&mut self, agent: &mut Agent, err: JsError) -> bool {
+        if agent.is_interrupt_requested() {
+            // An interrupted script cannot catch the error.
+            return false;
+        }
         if let Some(ejt) = self.exception_jump_target_stack.pop() {
             self.ip = ejt.ip;
             agent
@@ -1464,6 +1473,11 @@ impl<'a> Vm {
             }
             Instruction::Jump => {
                 let ip = instr.args[0].unwrap() as usize;
+                // Backward jumps are loop back-edges: check for interrupts so
+                // that infinite loops can be stopped.
+                if ip < vm.ip && agent.is_interrupt_requested() {
+                    return Err(agent.throw_interrupt(gc.nogc()));
+                }
                 vm.ip = ip;
             }
             Instruction::JumpIfNot => {
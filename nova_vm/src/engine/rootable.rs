@@ -4,6 +4,7 @@
 
 mod global;
 mod scoped;
+mod weak_global;
 
 use private::RootableSealed;
 
@@ -75,7 +76,7 @@ use crate::{
             SET_ITERATOR_DISCRIMINANT, STRING_DISCRIMINANT, SYMBOL_DISCRIMINANT,
         },
     },
-    heap::HeapMarkAndSweep,
+    heap::{HeapBits, HeapMarkAndSweep},
 };
 
 mod private {
@@ -170,6 +171,8 @@ mod private {
 
 pub use global::Global;
 pub use scoped::Scoped;
+pub use weak_global::WeakGlobal;
+pub(crate) use weak_global::WeakGlobalSlot;
 
 pub trait Rootable: std::fmt::Debug + Copy + RootableSealed {
     type RootRepr: Sized + Clone + std::fmt::Debug;
@@ -327,6 +330,109 @@ fn handle_heap_ref_overflow() -> ! {
     panic!("Heap references overflowed");
 }
 
+impl HeapRootData {
+    /// Returns true if the referenced heap data was marked as reachable
+    /// during the current garbage collection.
+    pub(crate) fn is_marked(&self, bits: &HeapBits) -> bool {
+        match *self {
+            HeapRootData::String(heap_string) => bits.strings[heap_string.get_index()],
+            HeapRootData::Symbol(symbol) => bits.symbols[symbol.get_index()],
+            HeapRootData::Number(heap_number) => bits.numbers[heap_number.get_index()],
+            HeapRootData::BigInt(heap_big_int) => bits.bigints[heap_big_int.get_index()],
+            HeapRootData::Object(ordinary_object) => bits.objects[ordinary_object.get_index()],
+            HeapRootData::BoundFunction(bound_function) => {
+                bits.bound_functions[bound_function.get_index()]
+            }
+            HeapRootData::BuiltinFunction(builtin_function) => {
+                bits.builtin_functions[builtin_function.get_index()]
+            }
+            HeapRootData::ECMAScriptFunction(ecmascript_function) => {
+                bits.ecmascript_functions[ecmascript_function.get_index()]
+            }
+            HeapRootData::BuiltinConstructorFunction(builtin_constructor_function) => {
+                bits.builtin_constructors[builtin_constructor_function.get_index()]
+            }
+            HeapRootData::BuiltinPromiseResolvingFunction(builtin_promise_resolving_function) => {
+                bits.promise_resolving_functions[builtin_promise_resolving_function.get_index()]
+            }
+            HeapRootData::PrimitiveObject(primitive_object) => {
+                bits.primitive_objects[primitive_object.get_index()]
+            }
+            HeapRootData::Arguments(ordinary_object) => bits.objects[ordinary_object.get_index()],
+            HeapRootData::Array(array) => bits.arrays[array.get_index()],
+            #[cfg(feature = "array-buffer")]
+            HeapRootData::ArrayBuffer(array_buffer) => bits.array_buffers[array_buffer.get_index()],
+            #[cfg(feature = "array-buffer")]
+            HeapRootData::DataView(data_view) => bits.data_views[data_view.get_index()],
+            #[cfg(feature = "date")]
+            HeapRootData::Date(date) => bits.dates[date.get_index()],
+            HeapRootData::Error(error) => bits.errors[error.get_index()],
+            HeapRootData::FinalizationRegistry(finalization_registry) => {
+                bits.finalization_registrys[finalization_registry.get_index()]
+            }
+            HeapRootData::Map(map) => bits.maps[map.get_index()],
+            HeapRootData::Promise(promise) => bits.promises[promise.get_index()],
+            HeapRootData::Proxy(proxy) => bits.proxys[proxy.get_index()],
+            #[cfg(feature = "regexp")]
+            HeapRootData::RegExp(reg_exp) => bits.regexps[reg_exp.get_index()],
+            HeapRootData::Set(set) => bits.sets[set.get_index()],
+            #[cfg(feature = "shared-array-buffer")]
+            HeapRootData::SharedArrayBuffer(shared_array_buffer) => {
+                bits.shared_array_buffers[shared_array_buffer.get_index()]
+            }
+            #[cfg(feature = "weak-refs")]
+            HeapRootData::WeakMap(weak_map) => bits.weak_maps[weak_map.get_index()],
+            #[cfg(feature = "weak-refs")]
+            HeapRootData::WeakRef(weak_ref) => bits.weak_refs[weak_ref.get_index()],
+            #[cfg(feature = "weak-refs")]
+            HeapRootData::WeakSet(weak_set) => bits.weak_sets[weak_set.get_index()],
+            #[cfg(feature = "array-buffer")]
+            HeapRootData::Int8Array(base_index) => bits.typed_arrays[base_index.into_index()],
+            #[cfg(feature = "array-buffer")]
+            HeapRootData::Uint8Array(base_index) => bits.typed_arrays[base_index.into_index()],
+            #[cfg(feature = "array-buffer")]
+            HeapRootData::Uint8ClampedArray(base_index) => {
+                bits.typed_arrays[base_index.into_index()]
+            }
+            #[cfg(feature = "array-buffer")]
+            HeapRootData::Int16Array(base_index) => bits.typed_arrays[base_index.into_index()],
+            #[cfg(feature = "array-buffer")]
+            HeapRootData::Uint16Array(base_index) => bits.typed_arrays[base_index.into_index()],
+            #[cfg(feature = "array-buffer")]
+            HeapRootData::Int32Array(base_index) => bits.typed_arrays[base_index.into_index()],
+            #[cfg(feature = "array-buffer")]
+            HeapRootData::Uint32Array(base_index) => bits.typed_arrays[base_index.into_index()],
+            #[cfg(feature = "array-buffer")]
+            HeapRootData::BigInt64Array(base_index) => bits.typed_arrays[base_index.into_index()],
+            #[cfg(feature = "array-buffer")]
+            HeapRootData::BigUint64Array(base_index) => bits.typed_arrays[base_index.into_index()],
+            #[cfg(feature = "array-buffer")]
+            HeapRootData::Float32Array(base_index) => bits.typed_arrays[base_index.into_index()],
+            #[cfg(feature = "array-buffer")]
+            HeapRootData::Float64Array(base_index) => bits.typed_arrays[base_index.into_index()],
+            HeapRootData::ArrayIterator(array_iterator) => {
+                bits.array_iterators[array_iterator.get_index()]
+            }
+            HeapRootData::SetIterator(set_iterator) => bits.set_iterators[set_iterator.get_index()],
+            HeapRootData::MapIterator(map_iterator) => bits.map_iterators[map_iterator.get_index()],
+            HeapRootData::Generator(generator) => bits.generators[generator.get_index()],
+            HeapRootData::Module(module) => bits.modules[module.get_index()],
+            HeapRootData::EmbedderObject(embedder_object) => {
+                bits.embedder_objects[embedder_object.get_index()]
+            }
+            // These variants are placeholders for objects that are not yet
+            // implemented: They have no heap data and no code creates them,
+            // so they can never have been rooted.
+            HeapRootData::BuiltinGeneratorFunction
+            | HeapRootData::BuiltinPromiseCollectorFunction
+            | HeapRootData::BuiltinProxyRevokerFunction
+            | HeapRootData::AsyncFromSyncIterator
+            | HeapRootData::AsyncIterator
+            | HeapRootData::Iterator => unreachable!(),
+        }
+    }
+}
+
 impl HeapMarkAndSweep for HeapRootData {
     fn mark_values(&self, queues: &mut crate::heap::WorkQueues) {
         match self {
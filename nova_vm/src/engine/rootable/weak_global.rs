@@ -0,0 +1,125 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::marker::PhantomData;
+
+use crate::engine::context::GcScope;
+use crate::{
+    ecmascript::execution::Agent,
+    engine::rootable::{HeapRootData, HeapRootRef, Rootable},
+};
+
+/// A slot in the heap's weak global list.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum WeakGlobalSlot {
+    /// The slot is not in use and can be given to a new WeakGlobal.
+    Free,
+    /// The slot's value was garbage collected but its WeakGlobal still
+    /// exists. The slot becomes free once the WeakGlobal is taken.
+    Cleared,
+    /// The slot holds a value that has not been garbage collected.
+    Live(HeapRootData),
+}
+
+/// # Weak global heap root
+///
+/// This type refers to a heap-allocated JavaScript engine value without
+/// keeping it alive. If the value is garbage collected, the WeakGlobal is
+/// cleared and accessing it returns `None`. This is useful for host-side
+/// caches that should not extend the lifetime of the values they cache.
+///
+/// Values that do not need rooting, such as small integers or small strings,
+/// are never collected and are always returned.
+#[derive(Debug, PartialEq)]
+pub struct WeakGlobal<T: Rootable>(T::RootRepr, PhantomData<T>);
+
+impl<T: Rootable> WeakGlobal<T> {
+    /// Register the given value into a WeakGlobal. The value is not kept
+    /// from being garbage collected.
+    #[must_use]
+    pub fn new(agent: &Agent, value: T) -> Self {
+        let value = match T::to_root_repr(value) {
+            Ok(stack_repr) => {
+                // The value doesn't need rooting.
+                return Self(stack_repr, PhantomData);
+            }
+            Err(heap_data) => heap_data,
+        };
+        let mut weak_globals = agent.heap.weak_globals.borrow_mut();
+        let reused_index = weak_globals
+            .iter_mut()
+            .enumerate()
+            .find_map(|(index, entry)| {
+                if matches!(entry, WeakGlobalSlot::Free) {
+                    *entry = WeakGlobalSlot::Live(value);
+                    Some(index)
+                } else {
+                    None
+                }
+            });
+        let heap_ref = if let Some(reused_index) = reused_index {
+            HeapRootRef::from_index(reused_index)
+        } else {
+            let next_index = weak_globals.len();
+            weak_globals.push(WeakGlobalSlot::Live(value));
+            HeapRootRef::from_index(next_index)
+        };
+        Self(T::from_heap_ref(heap_ref), Default::default())
+    }
+
+    /// Access the value referred to by this WeakGlobal, or `None` if the
+    /// value has been garbage collected.
+    pub fn get(&self, agent: &mut Agent, _: GcScope<'_, '_>) -> Option<T> {
+        let heap_ref = match T::from_root_repr(&self.0) {
+            Ok(value) => {
+                // The value didn't need rooting
+                return Some(value);
+            }
+            Err(heap_ref) => heap_ref,
+        };
+        let WeakGlobalSlot::Live(heap_data) = *agent
+            .heap
+            .weak_globals
+            .borrow()
+            .get(heap_ref.to_index())
+            .unwrap()
+        else {
+            return None;
+        };
+        let Some(value) = T::from_heap_data(heap_data) else {
+            panic!("Invalid WeakGlobal returned different type than expected");
+        };
+        Some(value)
+    }
+
+    /// Release this WeakGlobal, returning the value if it has not been
+    /// garbage collected. Using the WeakGlobal is not possible after this
+    /// call.
+    pub fn take(self, agent: &Agent) -> Option<T> {
+        let heap_ref = match T::from_root_repr(&self.0) {
+            Ok(value) => {
+                // The value didn't need rooting
+                return Some(value);
+            }
+            Err(heap_ref) => heap_ref,
+        };
+        // Free the slot and return the value
+        let slot = std::mem::replace(
+            agent
+                .heap
+                .weak_globals
+                .borrow_mut()
+                .get_mut(heap_ref.to_index())
+                .unwrap(),
+            WeakGlobalSlot::Free,
+        );
+        let WeakGlobalSlot::Live(heap_data) = slot else {
+            return None;
+        };
+        let Some(value) = T::from_heap_data(heap_data) else {
+            panic!("Invalid WeakGlobal returned different type than expected");
+        };
+        Some(value)
+    }
+}
@@ -86,11 +86,14 @@ use crate::{
             SymbolHeapData, BUILTIN_STRINGS_LIST,
         },
     },
-    engine::{rootable::HeapRootData, ExecutableHeapData},
+    engine::{
+        rootable::{HeapRootData, WeakGlobalSlot},
+        ExecutableHeapData,
+    },
 };
 #[cfg(feature = "array-buffer")]
 use ahash::AHashMap;
-pub(crate) use heap_bits::{CompactionLists, HeapMarkAndSweep, WorkQueues};
+pub(crate) use heap_bits::{CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues};
 
 #[derive(Debug)]
 pub struct Heap {
@@ -126,6 +129,7 @@ pub struct Heap {
     pub finalization_registrys: Vec<Option<FinalizationRegistryHeapData>>,
     pub generators: Vec<Option<GeneratorHeapData>>,
     pub(crate) globals: RefCell<Vec<Option<HeapRootData>>>,
+    pub(crate) weak_globals: RefCell<Vec<WeakGlobalSlot>>,
     pub maps: Vec<Option<MapHeapData>>,
     pub map_iterators: Vec<Option<MapIteratorHeapData>>,
     pub numbers: Vec<Option<NumberHeapData>>,
@@ -236,6 +240,7 @@ impl Heap {
             finalization_registrys: Vec::with_capacity(0),
             generators: Vec::with_capacity(1024),
             globals: RefCell::new(Vec::with_capacity(1024)),
+            weak_globals: RefCell::new(Vec::with_capacity(0)),
             maps: Vec::with_capacity(128),
             map_iterators: Vec::with_capacity(128),
             modules: Vec::with_capacity(0),
@@ -65,7 +65,7 @@ use crate::{
             BUILTIN_STRINGS_LIST,
         },
     },
-    engine::{context::GcScope, Executable},
+    engine::{context::GcScope, rootable::WeakGlobalSlot, Executable},
 };
 
 pub fn heap_gc(
@@ -82,6 +82,8 @@ pub fn heap_gc(
         symbol_id: _,
         global_symbol_registry: _,
         host_hooks: _,
+        interrupt_handle: _,
+        interrupt_error,
     } = agent;
     let mut bits = HeapBits::new(heap);
     let mut queues = WorkQueues::new(heap);
@@ -102,6 +104,7 @@ pub fn heap_gc(
     vm_stack.iter().for_each(|vm_ptr| {
         unsafe { vm_ptr.as_ref() }.mark_values(&mut queues);
     });
+    interrupt_error.mark_values(&mut queues);
     let mut last_filled_global_value = None;
     heap.globals
         .borrow()
@@ -170,6 +173,7 @@ pub fn heap_gc(
             finalization_registrys,
             generators,
             globals: _,
+            weak_globals: _,
             maps,
             map_iterators,
             modules,
@@ -1020,6 +1024,8 @@ fn sweep(
         symbol_id: _,
         global_symbol_registry: _,
         host_hooks: _,
+        interrupt_handle: _,
+        interrupt_error,
     } = agent;
 
     let Heap {
@@ -1052,6 +1058,7 @@ fn sweep(
         finalization_registrys,
         generators,
         globals,
+        weak_globals,
         maps,
         map_iterators,
         modules,
@@ -1107,12 +1114,31 @@ fn sweep(
 
     let mut globals = globals.borrow_mut();
     let globals_iter = globals.iter_mut();
+    let mut weak_globals = weak_globals.borrow_mut();
+    // Weak globals do not keep their values alive: Any weak global whose
+    // value was not marked is cleared before compaction. The slot stays
+    // reserved until the WeakGlobal is taken.
+    for slot in weak_globals.iter_mut() {
+        if let WeakGlobalSlot::Live(value) = slot {
+            if !value.is_marked(bits) {
+                *slot = WeakGlobalSlot::Cleared;
+            }
+        }
+    }
+    let weak_globals_iter = weak_globals.iter_mut();
     thread::scope(|s| {
         s.spawn(|| {
             for value in globals_iter {
                 value.sweep_values(&compactions);
             }
         });
+        s.spawn(|| {
+            for slot in weak_globals_iter {
+                if let WeakGlobalSlot::Live(value) = slot {
+                    value.sweep_values(&compactions);
+                }
+            }
+        });
         if !e2pow10.values.is_empty() {
             s.spawn(|| {
                 sweep_heap_elements_vector_descriptors(
@@ -1517,6 +1543,7 @@ fn sweep(
                 .iter_mut()
                 .for_each(|entry| unsafe { entry.as_mut().sweep_values(&compactions) });
         }
+        interrupt_error.sweep_values(&compactions);
     });
 }
 
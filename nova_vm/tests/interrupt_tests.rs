@@ -0,0 +1,117 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use nova_vm::ecmascript::{
+    execution::{
+        agent::{GcAgent, Options, RealmRoot},
+        DefaultHostHooks,
+    },
+    scripts_and_modules::script::{parse_script, script_evaluation},
+    types::String,
+};
+
+/// An exception thrown by a script.
+#[derive(Debug)]
+struct Thrown {
+    message: std::string::String,
+    is_interrupt: bool,
+}
+
+/// Evaluates a script, returning the exception if it threw.
+fn run(agent: &mut GcAgent, realm: &RealmRoot, source: &str) -> Result<(), Thrown> {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let realm = agent.current_realm_id();
+        let source_text = String::from_string(agent, source.to_owned(), gc.nogc());
+        let script = parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        match script_evaluation(agent, script, gc.reborrow()) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Thrown {
+                is_interrupt: err.is_interrupt(agent),
+                message: err.format(agent, gc),
+            }),
+        }
+    })
+}
+
+/// Requests an interrupt from another thread after a delay.
+fn interrupt_after(agent: &GcAgent, delay: Duration) -> thread::JoinHandle<()> {
+    let handle = agent.interrupt_handle();
+    thread::spawn(move || {
+        thread::sleep(delay);
+        handle.request_interrupt();
+    })
+}
+
+#[test]
+fn interrupt_infinite_loop() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let interrupter = interrupt_after(&agent, Duration::from_millis(50));
+    let start = Instant::now();
+    let result = run(&mut agent, &realm, "while (true) {}");
+    interrupter.join().unwrap();
+    assert!(start.elapsed() < Duration::from_secs(10));
+    let thrown = result.unwrap_err();
+    assert!(thrown.is_interrupt);
+    assert_eq!(thrown.message, "Error: Script execution was interrupted");
+    assert!(agent.interrupt_handle().is_interrupt_requested());
+}
+
+#[test]
+fn interrupt_is_distinct_from_script_errors() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let thrown = run(
+        &mut agent,
+        &realm,
+        "throw new Error('Script execution was interrupted');",
+    )
+    .unwrap_err();
+    assert!(!thrown.is_interrupt);
+    agent.request_interrupt();
+    let thrown = run(&mut agent, &realm, "function f() {} f();").unwrap_err();
+    assert!(thrown.is_interrupt);
+    agent.clear_interrupt();
+    assert!(run(&mut agent, &realm, "throw new Error('boom');")
+        .is_err_and(|thrown| !thrown.is_interrupt));
+}
+
+#[test]
+fn interrupt_cannot_be_caught() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let interrupter = interrupt_after(&agent, Duration::from_millis(50));
+    let result = run(
+        &mut agent,
+        &realm,
+        "
+        function spin() {
+            for (;;) {}
+        }
+        while (true) {
+            try {
+                spin();
+            } catch {}
+        }
+        ",
+    );
+    interrupter.join().unwrap();
+    assert!(result.is_err());
+}
+
+#[test]
+fn clear_interrupt() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent.request_interrupt();
+    assert!(run(&mut agent, &realm, "function f() {} f();").is_err());
+    assert!(run(&mut agent, &realm, "1 + 1").is_err());
+    agent.clear_interrupt();
+    assert!(run(&mut agent, &realm, "let i = 0; while (i < 10) { i++; }").is_ok());
+}
@@ -0,0 +1,61 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::ecmascript::{
+    execution::{
+        agent::{GcAgent, Options},
+        DefaultHostHooks,
+    },
+    scripts_and_modules::script::{parse_script, script_evaluation},
+    types::String,
+};
+
+/// Evaluates a script that throws and returns the formatted exception.
+fn format_thrown(source: &str) -> std::string::String {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm_id();
+        let source_text = String::from_string(agent, source.to_owned(), gc.nogc());
+        let script = parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        let error =
+            script_evaluation(agent, script, gc.reborrow()).expect_err("script should have thrown");
+        error.format(agent, gc)
+    })
+}
+
+#[test]
+fn format_error() {
+    assert_eq!(
+        format_thrown("throw new TypeError('not a function')"),
+        "TypeError: not a function"
+    );
+    assert_eq!(
+        format_thrown("null.foo"),
+        format_thrown("try { null.foo } catch (err) { throw err }")
+    );
+    assert!(format_thrown("null.foo").starts_with("TypeError: "));
+}
+
+#[test]
+fn format_non_error_value() {
+    assert_eq!(format_thrown("throw 5"), "5");
+    assert_eq!(format_thrown("throw 'oops'"), "oops");
+}
+
+#[test]
+fn format_custom_to_string() {
+    assert_eq!(
+        format_thrown("throw { toString() { return 'custom'; } }"),
+        "custom"
+    );
+}
+
+#[test]
+fn format_throwing_to_string() {
+    assert_eq!(
+        format_thrown("throw { toString() { throw new Error('nested'); } }"),
+        "Object"
+    );
+}
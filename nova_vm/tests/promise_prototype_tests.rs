@@ -0,0 +1,69 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{cell::RefCell, collections::VecDeque, fmt::Debug, fs, path::PathBuf};
+
+use nova_vm::ecmascript::{
+    execution::agent::{GcAgent, HostHooks, Job, Options},
+    scripts_and_modules::script::{parse_script, script_evaluation},
+    types::String,
+};
+
+#[derive(Default)]
+struct TestHostHooks {
+    promise_job_queue: RefCell<VecDeque<Job>>,
+}
+
+// RefCell doesn't implement Debug
+impl Debug for TestHostHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TestHostHooks").finish()
+    }
+}
+
+impl HostHooks for TestHostHooks {
+    fn enqueue_promise_job(&self, job: Job) {
+        self.promise_job_queue.borrow_mut().push_back(job);
+    }
+}
+
+#[test]
+fn promise_prototype_tests() {
+    let d: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "sources",
+        "promisePrototype.test.js",
+    ]
+    .iter()
+    .collect();
+    let contents = fs::read_to_string(d.clone()).expect("Should have been able to read the file");
+
+    let host_hooks: &TestHostHooks = &*Box::leak(Box::default());
+    let mut agent = GcAgent::new(Options::default(), host_hooks);
+    let realm = agent.create_default_realm();
+    for source in [contents, "checkAfterJobs();".to_string()] {
+        agent.run_in_realm(&realm, |agent, mut gc| {
+            let realm = agent.current_realm_id();
+            let source_text = String::from_string(agent, source, gc.nogc());
+            let script = parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+            let mut result = script_evaluation(agent, script, gc.reborrow());
+            while result.is_ok() {
+                let Some(job) = host_hooks.promise_job_queue.borrow_mut().pop_front() else {
+                    break;
+                };
+                if let Err(err) = job.run(agent, gc.reborrow()) {
+                    result = Err(err);
+                }
+            }
+            let _ = result.unwrap_or_else(|err| {
+                panic!(
+                    "Test '{}' failed: {:?}",
+                    d.display(),
+                    err.to_string(agent, gc).as_str(agent)
+                )
+            });
+        });
+    }
+}
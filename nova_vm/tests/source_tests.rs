@@ -13,16 +13,12 @@ use nova_vm::ecmascript::{
     types::String,
 };
 
-#[test]
-fn object_prototype_tests() {
-    let d: PathBuf = [
-        env!("CARGO_MANIFEST_DIR"),
-        "tests",
-        "sources",
-        "objectPrototype.test.js",
-    ]
-    .iter()
-    .collect();
+/// Evaluates the script `tests/sources/<file_name>` in a fresh realm. The
+/// scripts throw an error if any of their checks fail.
+fn run_source_test(file_name: &str) {
+    let d: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "sources", file_name]
+        .iter()
+        .collect();
     let contents = fs::read_to_string(d.clone()).expect("Should have been able to read the file");
 
     let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
@@ -40,3 +36,58 @@ fn object_prototype_tests() {
         });
     });
 }
+
+#[test]
+fn array_prototype_tests() {
+    run_source_test("arrayPrototype.test.js");
+}
+
+#[test]
+fn date_tests() {
+    run_source_test("date.test.js");
+}
+
+#[test]
+fn global_object_tests() {
+    run_source_test("globalObject.test.js");
+}
+
+#[test]
+fn json_tests() {
+    run_source_test("json.test.js");
+}
+
+#[test]
+fn keyed_collections_tests() {
+    run_source_test("keyedCollections.test.js");
+}
+
+#[test]
+fn math_tests() {
+    run_source_test("math.test.js");
+}
+
+#[test]
+fn object_constructor_tests() {
+    run_source_test("objectConstructor.test.js");
+}
+
+#[test]
+fn object_prototype_tests() {
+    run_source_test("objectPrototype.test.js");
+}
+
+#[test]
+fn reflect_tests() {
+    run_source_test("reflect.test.js");
+}
+
+#[test]
+fn string_constructor_tests() {
+    run_source_test("stringConstructor.test.js");
+}
+
+#[test]
+fn string_prototype_tests() {
+    run_source_test("stringPrototype.test.js");
+}
@@ -0,0 +1,442 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertThrows(errorType, fn, message) {
+  try {
+    fn();
+  } catch (err) {
+    if (err instanceof errorType) {
+      return;
+    }
+    throw new Error(`${message}: threw the wrong error type`);
+  }
+  throw new Error(`${message}: did not throw`);
+}
+
+// Array.prototype.at and Array.prototype.with
+
+assertThrows(TypeError, () => [1, 2, 3].at(1n), "'at(1n)'");
+assertThrows(TypeError, () => [1, 2, 3].with(0n, 5), "'with(0n, 5)'");
+if ([1, 2, 3].at("1") !== 2) {
+  throw new Error("'at(\"1\")' did not coerce the numeric string index");
+}
+if ([1, 2, 3].with("1", 5).join() !== "1,5,3") {
+  throw new Error("'with(\"1\", 5)' did not coerce the numeric string index");
+}
+if ([1, 2, 3].at(Infinity) !== undefined) {
+  throw new Error("'at(Infinity)' did not return undefined");
+}
+if ([1, 2, 3].at(-Infinity) !== undefined) {
+  throw new Error("'at(-Infinity)' did not return undefined");
+}
+assertThrows(RangeError, () => [1, 2, 3].with(Infinity, 5), "'with(Infinity, 5)'");
+assertThrows(RangeError, () => [1, 2, 3].with(-Infinity, 5), "'with(-Infinity, 5)'");
+
+// Array.prototype.sort
+
+const sparse = [3, undefined, , 1, , undefined, 2, , ];
+const sparseResult = sparse.sort();
+if (sparseResult !== sparse) {
+  throw new Error("'sort' did not return the this value");
+}
+if (sparse.length !== 8) {
+  throw new Error("'sort' changed the length of a sparse array");
+}
+for (const index of [0, 1, 2]) {
+  if (!sparse.hasOwnProperty(index) || sparse[index] !== index + 1) {
+    throw new Error(`'sort' did not place ${index + 1} at index ${index}`);
+  }
+}
+for (const index of [3, 4]) {
+  if (!sparse.hasOwnProperty(index) || sparse[index] !== undefined) {
+    throw new Error(`'sort' did not place undefined at index ${index}`);
+  }
+}
+for (const index of [5, 6, 7]) {
+  if (sparse.hasOwnProperty(index)) {
+    throw new Error(`'sort' did not leave a hole at index ${index}`);
+  }
+}
+
+let lengthReads = 0;
+const arrayLike = {
+  get length() {
+    lengthReads++;
+    return 5;
+  },
+  0: "b",
+  2: "a",
+  4: undefined,
+};
+Array.prototype.sort.call(arrayLike);
+if (lengthReads !== 1) {
+  throw new Error("'sort' did not read length exactly once");
+}
+if (arrayLike[0] !== "a" || arrayLike[1] !== "b") {
+  throw new Error("'sort' did not sort an array-like object");
+}
+if (!arrayLike.hasOwnProperty(2) || arrayLike[2] !== undefined) {
+  throw new Error("'sort' did not move undefined after the sorted elements");
+}
+if (arrayLike.hasOwnProperty(3) || arrayLike.hasOwnProperty(4)) {
+  throw new Error("'sort' did not delete the trailing slots");
+}
+
+// Array.prototype.flat
+
+const nested = [1, [2, [3, [4]]]];
+if (nested.flat().length !== 3 || !Array.isArray(nested.flat()[2])) {
+  throw new Error("'flat()' did not default to a depth of 1");
+}
+const flatTwo = nested.flat("2");
+if (flatTwo.length !== 4 || flatTwo[2] !== 3 || !Array.isArray(flatTwo[3])) {
+  throw new Error("'flat(\"2\")' did not flatten two levels");
+}
+for (const depth of [NaN, -5, -Infinity]) {
+  const shallow = nested.flat(depth);
+  if (shallow === nested || shallow.length !== 2 || shallow[1] !== nested[1]) {
+    throw new Error(`'flat(${depth})' did not produce a shallow copy`);
+  }
+}
+const flatInfinity = nested.flat(Infinity);
+if (flatInfinity.join() !== "1,2,3,4" || flatInfinity.length !== 4) {
+  throw new Error("'flat(Infinity)' did not fully flatten");
+}
+
+// Array.prototype.reduce
+
+let reduceCalls = 0;
+const countingReducer = (accumulator, value) => {
+  reduceCalls++;
+  return accumulator + value;
+};
+if (Array.prototype.reduce.call({ length: 1, 0: 5 }, countingReducer) !== 5) {
+  throw new Error("'reduce' did not return the only present element");
+}
+if (reduceCalls !== 0) {
+  throw new Error("'reduce' called the callback for a single element");
+}
+if ([1].reduce(countingReducer, 10) !== 11 || reduceCalls !== 1) {
+  throw new Error("'reduce' did not call the callback once with an initial value");
+}
+if ([].reduce(countingReducer, 7) !== 7 || reduceCalls !== 1) {
+  throw new Error("'reduce' did not return the initial value for an empty array");
+}
+assertThrows(TypeError, () => [].reduce(countingReducer), "'reduce' on an empty array");
+assertThrows(
+  TypeError,
+  () => Array.prototype.reduce.call({ length: 3 }, countingReducer),
+  "'reduce' on an array-like with only holes",
+);
+const visited = [];
+const reducedSparse = Array.prototype.reduce.call(
+  { length: 5, 1: "a", 3: "b" },
+  (accumulator, value, index) => {
+    visited.push(index);
+    return accumulator + value;
+  },
+  "",
+);
+if (reducedSparse !== "ab" || visited.join() !== "1,3") {
+  throw new Error("'reduce' did not skip holes in an array-like");
+}
+
+if ([1, 2, 3].includes(1, 1) || ![1, 2, 1].includes(1, 1)) {
+  throw new Error("'includes' did not start searching at a positive fromIndex");
+}
+// String indices skip the dense array fast path.
+const includesDense = [];
+for (let i = 0; i < 10000; i++) {
+  includesDense.push(i % 7 === 0 ? NaN : i);
+}
+for (const fromIndex of [-10001, -5000, -1, 0, 1, 4999, 9999, 10000]) {
+  for (const value of [NaN, 9999, 5, 0, -0, "5"]) {
+    const fast = includesDense.includes(value, fromIndex);
+    const slow = includesDense.includes(value, String(fromIndex));
+    if (fast !== slow) {
+      throw new Error(`'includes(${value}, ${fromIndex})' fast path gave ${fast}, slow path gave ${slow}`);
+    }
+  }
+}
+
+// Array holes
+
+const holey = [, 1];
+if (0 in holey || !(1 in holey)) {
+  throw new Error("'in' did not tell a hole from an element");
+}
+if (holey.hasOwnProperty(0) || !holey.hasOwnProperty(1)) {
+  throw new Error("'hasOwnProperty' reported a hole as an own property");
+}
+if (Object.keys(holey).length !== 1 || Object.keys(holey)[0] !== "1") {
+  throw new Error("'Object.keys([, 1])' did not return [\"1\"]");
+}
+if (Object.getOwnPropertyNames(holey).join() !== "1,length") {
+  throw new Error("'Object.getOwnPropertyNames([, 1])' did not return [\"1\", \"length\"]");
+}
+holey[0] = undefined;
+if (!(0 in holey) || !holey.hasOwnProperty(0) || Object.keys(holey).join() !== "0,1") {
+  throw new Error("Assigning undefined did not fill the hole");
+}
+const deleted = [1, 2, 3];
+delete deleted[1];
+if (1 in deleted || deleted.length !== 3 || Object.keys(deleted).join() !== "0,2") {
+  throw new Error("'delete' did not leave a hole");
+}
+
+// Array.prototype.flat element reads
+
+const flatGetterLog = [];
+const flatGetterSource = [];
+function defineLoggingElement(array, index, value) {
+  Object.defineProperty(array, index, {
+    get() {
+      flatGetterLog.push(index);
+      return value;
+    },
+    enumerable: true,
+    configurable: true,
+  });
+}
+const arrayLikeElement = { length: 2, 0: "a", 1: "b" };
+defineLoggingElement(flatGetterSource, 0, 1);
+defineLoggingElement(flatGetterSource, 1, [2, [3]]);
+defineLoggingElement(flatGetterSource, 2, arrayLikeElement);
+flatGetterSource.length = 4;
+const flatGetterResult = flatGetterSource.flat();
+if (flatGetterLog.join() !== "0,1,2") {
+  throw new Error("'flat' did not read each element through its getter exactly once");
+}
+if (
+  flatGetterResult.length !== 4 ||
+  flatGetterResult[0] !== 1 ||
+  flatGetterResult[1] !== 2 ||
+  !Array.isArray(flatGetterResult[2]) ||
+  flatGetterResult[3] !== arrayLikeElement
+) {
+  throw new Error("'flat' did not flatten an array returned by a getter or skip the hole");
+}
+const flatMapGetterLog = [];
+const flatMapGetterSource = [];
+Object.defineProperty(flatMapGetterSource, 0, {
+  get() {
+    flatMapGetterLog.push("get");
+    return 1;
+  },
+});
+flatMapGetterSource.length = 1;
+const flatMapGetterResult = flatMapGetterSource.flatMap((x) => {
+  flatMapGetterLog.push("map");
+  return [x, [x]];
+});
+if (flatMapGetterLog.join() !== "get,map" || flatMapGetterResult.length !== 2) {
+  throw new Error("'flatMap' did not read the element through its getter before mapping");
+}
+if ([1n, 2n].indexOf(1) !== -1) {
+  throw new Error("'indexOf' matched a BigInt element with a Number search value");
+}
+if ([1n].indexOf(1n) !== 0) {
+  throw new Error("'indexOf' did not find a BigInt element");
+}
+if ([1].includes(1n) !== false) {
+  throw new Error("'includes' matched a Number element with a BigInt search value");
+}
+if ([1n].includes(1) !== false) {
+  throw new Error("'includes' matched a BigInt element with a Number search value");
+}
+const mixedNumerics = [1n, 1, 0, 0n, 2n ** 64n, 2 ** 64];
+if (mixedNumerics.indexOf(1) !== 1 || mixedNumerics.indexOf(1n) !== 0) {
+  throw new Error("'indexOf' did not distinguish BigInt and Number elements");
+}
+if (mixedNumerics.indexOf(0n) !== 3 || mixedNumerics.indexOf(-0) !== 2) {
+  throw new Error("'indexOf' did not distinguish BigInt and Number zeroes");
+}
+if (
+  mixedNumerics.indexOf(2 ** 64) !== 5 ||
+  mixedNumerics.indexOf(2n ** 64n) !== 4
+) {
+  throw new Error("'indexOf' did not distinguish large BigInt and Number elements");
+}
+if (
+  mixedNumerics.lastIndexOf(1) !== 1 ||
+  mixedNumerics.lastIndexOf(1n) !== 0 ||
+  mixedNumerics.lastIndexOf(0) !== 2
+) {
+  throw new Error("'lastIndexOf' did not distinguish BigInt and Number elements");
+}
+if (
+  !mixedNumerics.includes(0n) ||
+  !mixedNumerics.includes(2n ** 64n) ||
+  mixedNumerics.includes(2n) ||
+  mixedNumerics.includes(2)
+) {
+  throw new Error("'includes' did not distinguish BigInt and Number elements");
+}
+
+// Array.prototype.concat
+
+const concatenated = [1, 2].concat([3, 4, 5], [6], [[7]], 8);
+if (concatenated.length !== 8 || concatenated.join() !== "1,2,3,4,5,6,7,8") {
+  throw new Error("'concat' did not concatenate several arrays");
+}
+if (!Array.isArray(concatenated[6]) || concatenated[6][0] !== 7) {
+  throw new Error("'concat' spread a nested array");
+}
+const maxLengthLike = {
+  length: 2 ** 53 - 2,
+  [Symbol.isConcatSpreadable]: true,
+};
+assertThrows(
+  TypeError,
+  () => [1, 2].concat(maxLengthLike),
+  "'concat' past the maximum array-like length",
+);
+
+// Array.prototype.flat depth handling
+
+const flatSource = [1, [2, [3]]];
+if (flatSource.flat().length !== 3 || !Array.isArray(flatSource.flat()[2])) {
+  throw new Error("'[1, [2, [3]]].flat()' did not flatten one level");
+}
+if (flatSource.flat(2).join() !== "1,2,3" || flatSource.flat(2).length !== 3) {
+  throw new Error("'[1, [2, [3]]].flat(2)' did not flatten two levels");
+}
+if (flatSource.flat(Infinity).length !== 3) {
+  throw new Error("'[1, [2, [3]]].flat(Infinity)' did not fully flatten");
+}
+if (flatSource.flat(-1).length !== 2 || flatSource.flat(-1)[1] !== flatSource[1]) {
+  throw new Error("'[1, [2, [3]]].flat(-1)' did not produce a shallow copy");
+}
+let deeplyNested = ["innermost"];
+for (let i = 0; i < 10000; i++) {
+  deeplyNested = [deeplyNested];
+}
+const deeplyFlattened = deeplyNested.flat(Infinity);
+if (deeplyFlattened.length !== 1 || deeplyFlattened[0] !== "innermost") {
+  throw new Error("'flat(Infinity)' did not flatten a deeply nested array");
+}
+if ([[1], 2, [[3]]].flatMap((x) => x).length !== 3) {
+  throw new Error("'flatMap' flattened more than one level");
+}
+
+// Array.prototype.includes and Array.prototype.indexOf
+
+if (![NaN].includes(NaN)) {
+  throw new Error("'[NaN].includes(NaN)' did not return true");
+}
+if ([NaN].indexOf(NaN) !== -1) {
+  throw new Error("'[NaN].indexOf(NaN)' did not return -1");
+}
+if (![-0].includes(0) || [-0].indexOf(0) !== 0) {
+  throw new Error("'includes' or 'indexOf' distinguished -0 from +0");
+}
+if (!Array.prototype.includes.call({ length: 1, 0: NaN }, NaN)) {
+  throw new Error("'includes' did not find NaN in an array-like");
+}
+if ([1, 2, 3].includes(1, -2) || ![1, 2, 3].includes(2, -2)) {
+  throw new Error("'includes' did not offset a negative fromIndex from the end");
+}
+if ([1, 2, 3].indexOf(1, -2) !== -1 || [1, 2, 3].indexOf(3, -1) !== 2) {
+  throw new Error("'indexOf' did not offset a negative fromIndex from the end");
+}
+if (![1, 2, 3].includes(1, -10) || [1, 2, 3].indexOf(1, -10) !== 0) {
+  throw new Error("'includes' or 'indexOf' did not clamp a fromIndex below -length");
+}
+if ([1, 2, 3].includes(3, 5) || [1, , 3].indexOf(3, 5) !== -1) {
+  throw new Error("'includes' or 'indexOf' searched past a fromIndex beyond length");
+}
+if (![1, 2].includes(1, "0") || [1, 2].indexOf(1, 0.5) !== 0) {
+  throw new Error("'includes' or 'indexOf' did not coerce a non-integer fromIndex");
+}
+
+// Array.prototype.fill
+
+const filled = [1, 2, 3, 4];
+if (filled.fill(0, 1, 3) !== filled || filled.join() !== "1,0,0,4") {
+  throw new Error("'fill' did not return the filled array");
+}
+// String indices skip the dense array fast path.
+for (const range of [[1, 3], [-1, undefined], [2, 1], [5, 10], [-10, -2]]) {
+  const start = range[0];
+  const end = range[1];
+  const fast = [1, 2, 3, 4].fill(9, start, end).join();
+  const slow = [1, 2, 3, 4].fill(9, String(start), end === undefined ? end : String(end)).join();
+  if (fast !== slow) {
+    throw new Error(`'fill(9, ${start}, ${end})' fast path gave ${fast}, slow path gave ${slow}`);
+  }
+}
+const proxyTraps = [];
+const proxied = new Proxy([1, 2, 3], {
+  set(target, key, value) {
+    proxyTraps.push(key);
+    target[key] = value;
+    return true;
+  },
+});
+Array.prototype.fill.call(proxied, 7, 1);
+if (proxyTraps.join() !== "1,2" || proxied.join() !== "1,7,7") {
+  throw new Error("'fill' on a Proxy did not go through the set trap");
+}
+const withSetter = [1, 2, 3];
+let setterValue;
+Object.defineProperty(withSetter, 1, {
+  get() {
+    return 5;
+  },
+  set(value) {
+    setterValue = value;
+  },
+});
+withSetter.fill(8);
+if (setterValue !== 8 || withSetter.join() !== "8,5,8") {
+  throw new Error("'fill' did not call an element setter");
+}
+assertThrows(TypeError, () => Object.freeze([1, 2]).fill(0), "'fill' on a frozen array");
+
+// Array.prototype.sort stability and comparator errors
+
+const tagged = [];
+for (let i = 0; i < 2000; i++) {
+  tagged.push({ key: (i * 7) % 5, tag: i });
+}
+tagged.sort((a, b) => a.key - b.key);
+for (let i = 1; i < tagged.length; i++) {
+  const previous = tagged[i - 1];
+  const current = tagged[i];
+  if (previous.key > current.key) {
+    throw new Error("'sort' did not order the keys");
+  }
+  if (previous.key === current.key && previous.tag > current.tag) {
+    throw new Error("'sort' reordered elements with equal keys");
+  }
+}
+class ComparatorError extends Error {}
+const partiallySorted = [5, 4, 3, 2, 1];
+let comparisons = 0;
+assertThrows(
+  ComparatorError,
+  () =>
+    partiallySorted.sort((a, b) => {
+      if (++comparisons > 3) {
+        throw new ComparatorError("stop");
+      }
+      return a - b;
+    }),
+  "'sort' with a throwing comparator",
+);
+if (partiallySorted.length !== 5 || partiallySorted.toSorted().join() !== "1,2,3,4,5") {
+  throw new Error("'sort' with a throwing comparator lost elements");
+}
+assertThrows(
+  ComparatorError,
+  () =>
+    [1, 2].toSorted(() => {
+      throw new ComparatorError("stop");
+    }),
+  "'toSorted' with a throwing comparator",
+);
+const withUndefined = [3, undefined, 1].sort();
+if (withUndefined.length !== 3 || withUndefined.join() !== "1,3," || !(2 in withUndefined)) {
+  throw new Error("'[3, undefined, 1].sort()' did not move undefined to the end");
+}
@@ -0,0 +1,164 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+{
+  const date = new Date(2020, 11, 31, 23, 59, 58, 123);
+  if (date.getTime() !== 1609459198123) {
+    throw new Error("'new Date(2020, 11, 31, 23, 59, 58, 123)' did not have the right time value");
+  }
+  if (date.valueOf() !== date.getTime()) {
+    throw new Error("'Date.prototype.valueOf' did not return the time value");
+  }
+  if (
+    date.getFullYear() !== 2020 ||
+    date.getMonth() !== 11 ||
+    date.getDate() !== 31 ||
+    date.getDay() !== 4 ||
+    date.getHours() !== 23 ||
+    date.getMinutes() !== 59 ||
+    date.getSeconds() !== 58 ||
+    date.getMilliseconds() !== 123
+  ) {
+    throw new Error("Date getters did not return the components of the time value");
+  }
+  if (date.getTimezoneOffset() !== 0) {
+    throw new Error("'Date.prototype.getTimezoneOffset' did not return 0");
+  }
+}
+
+{
+  if (Date.UTC(2020, 10, 15) !== 1605398400000) {
+    throw new Error("'Date.UTC(2020, 10, 15)' did not return the right time value");
+  }
+  if (Date.UTC(99) !== Date.UTC(1999)) {
+    throw new Error("'Date.UTC(99)' did not map the year to 1999");
+  }
+  const date = new Date(Date.UTC(2020, 10, 15));
+  if (date.setUTCMonth(13) !== 1613347200000) {
+    throw new Error("'Date.prototype.setUTCMonth(13)' did not return the new time value");
+  }
+  if (date.getUTCFullYear() !== 2021 || date.getUTCMonth() !== 1) {
+    throw new Error("'Date.prototype.setUTCMonth(13)' did not roll over into the next year");
+  }
+  date.setUTCHours(1, 2);
+  if (date.getUTCHours() !== 1 || date.getUTCMinutes() !== 2 || date.getUTCSeconds() !== 0) {
+    throw new Error("'Date.prototype.setUTCHours(1, 2)' did not keep the seconds");
+  }
+  if (date.setTime(1000) !== 1000 || date.getTime() !== 1000) {
+    throw new Error("'Date.prototype.setTime(1000)' did not set the time value");
+  }
+  if (new Date(date).getTime() !== 1000) {
+    throw new Error("'new Date(date)' did not copy the time value");
+  }
+}
+
+{
+  const date = new Date(-1);
+  if (date.getUTCFullYear() !== 1969 || date.getUTCMilliseconds() !== 999) {
+    throw new Error("Date getters did not handle time values before the epoch");
+  }
+  if (date.toISOString() !== "1969-12-31T23:59:59.999Z") {
+    throw new Error("'Date.prototype.toISOString' did not handle time values before the epoch");
+  }
+  if (new Date(8.64e15).toISOString() !== "+275760-09-13T00:00:00.000Z") {
+    throw new Error("'Date.prototype.toISOString' did not use an expanded year");
+  }
+  if (new Date(-62198755200000).toISOString() !== "-000001-01-01T00:00:00.000Z") {
+    throw new Error("'Date.prototype.toISOString' did not handle negative years");
+  }
+  if (!Number.isNaN(new Date(8.64e15 + 1).getTime())) {
+    throw new Error("'new Date(8.64e15 + 1)' was not an invalid Date");
+  }
+}
+
+{
+  const date = new Date(Date.UTC(2020, 11, 31, 23, 59, 58, 123));
+  if (date.toString() !== "Thu Dec 31 2020 23:59:58 GMT+0000 (Coordinated Universal Time)") {
+    throw new Error("'Date.prototype.toString' did not return the right string");
+  }
+  if (date.toDateString() !== "Thu Dec 31 2020") {
+    throw new Error("'Date.prototype.toDateString' did not return the right string");
+  }
+  if (date.toTimeString() !== "23:59:58 GMT+0000 (Coordinated Universal Time)") {
+    throw new Error("'Date.prototype.toTimeString' did not return the right string");
+  }
+  if (date.toUTCString() !== "Thu, 31 Dec 2020 23:59:58 GMT") {
+    throw new Error("'Date.prototype.toUTCString' did not return the right string");
+  }
+  if (date.toISOString() !== "2020-12-31T23:59:58.123Z" || date.toJSON() !== date.toISOString()) {
+    throw new Error("'Date.prototype.toISOString' did not return the right string");
+  }
+  if (typeof Date() !== "string") {
+    throw new Error("'Date()' did not return a string");
+  }
+}
+
+{
+  const date = new Date(NaN);
+  if (
+    !Number.isNaN(date.getTime()) ||
+    !Number.isNaN(date.getFullYear()) ||
+    !Number.isNaN(date.getUTCDate()) ||
+    !Number.isNaN(date.getTimezoneOffset())
+  ) {
+    throw new Error("Date getters did not return NaN for an invalid Date");
+  }
+  if (date.toString() !== "Invalid Date" || date.toUTCString() !== "Invalid Date") {
+    throw new Error("'Date.prototype.toString' did not return 'Invalid Date'");
+  }
+  if (date.toJSON() !== null) {
+    throw new Error("'Date.prototype.toJSON' did not return null for an invalid Date");
+  }
+  let threw = false;
+  try {
+    date.toISOString();
+  } catch (err) {
+    threw = err instanceof RangeError;
+  }
+  if (!threw) {
+    throw new Error("'Date.prototype.toISOString' did not throw a RangeError for an invalid Date");
+  }
+  if (!Number.isNaN(date.setMonth(1))) {
+    throw new Error("'Date.prototype.setMonth' did not keep the Date invalid");
+  }
+  if (date.setFullYear(2000) !== 946684800000) {
+    throw new Error("'Date.prototype.setFullYear' did not start from +0 for an invalid Date");
+  }
+}
+
+{
+  if (new Date("2020-01-01").getTime() !== Date.UTC(2020, 0, 1)) {
+    throw new Error("'new Date' did not parse a date-only string as UTC");
+  }
+  if (Date.parse("2020-02") !== Date.UTC(2020, 1, 1) || Date.parse("2020") !== Date.UTC(2020, 0, 1)) {
+    throw new Error("'Date.parse' did not default the missing month and day");
+  }
+  if (Date.parse("2020-01-01T12:34:56.789Z") !== Date.UTC(2020, 0, 1, 12, 34, 56, 789)) {
+    throw new Error("'Date.parse' did not parse a UTC date-time string");
+  }
+  if (Date.parse("2020-01-01T01:00:00+01:00") !== Date.UTC(2020, 0, 1)) {
+    throw new Error("'Date.parse' did not apply the UTC offset");
+  }
+  if (Date.parse("2020-01-01T00:00:00.5Z") !== Date.UTC(2020, 0, 1, 0, 0, 0, 500)) {
+    throw new Error("'Date.parse' did not parse a short fraction of a second");
+  }
+  if (Date.parse("2020-01-01T24:00Z") !== Date.UTC(2020, 0, 2)) {
+    throw new Error("'Date.parse' did not parse 24:00 as the end of the day");
+  }
+  if (Date.parse("+002020-01-01") !== Date.UTC(2020, 0, 1)) {
+    throw new Error("'Date.parse' did not parse an expanded year");
+  }
+  const date = new Date(Date.UTC(-1, 5, 15, 8, 30));
+  if (Date.parse(date.toISOString()) !== date.getTime()) {
+    throw new Error("'Date.parse' did not round-trip 'Date.prototype.toISOString'");
+  }
+  for (const string of ["2020-13-01", "2020-01-01T12:60Z", "2020-01-01T24:00:01Z", "-000000-01-01", "+275760-09-13T00:00:00.001Z", "abc", ""]) {
+    if (!Number.isNaN(Date.parse(string))) {
+      throw new Error(`'Date.parse' did not return NaN for '${string}'`);
+    }
+  }
+  if (new Date("abc").toString() !== "Invalid Date") {
+    throw new Error("'new Date' did not create an invalid Date from an unparseable string");
+  }
+}
@@ -0,0 +1,91 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// Global bindings
+
+assignedGlobal = 1;
+if (!Object.getOwnPropertyDescriptor(globalThis, "assignedGlobal").configurable) {
+  throw new Error("Assigning an undeclared name created a non-configurable global property");
+}
+if (!delete globalThis.assignedGlobal || "assignedGlobal" in globalThis) {
+  throw new Error("A global property created by assignment could not be deleted");
+}
+
+var varGlobal = 1;
+if (Object.getOwnPropertyDescriptor(globalThis, "varGlobal").configurable) {
+  throw new Error("'var' created a configurable global property");
+}
+if (delete globalThis.varGlobal || !("varGlobal" in globalThis)) {
+  throw new Error("A global property created by 'var' was deleted");
+}
+
+function functionGlobal() {}
+if (Object.getOwnPropertyDescriptor(globalThis, "functionGlobal").configurable) {
+  throw new Error("A function declaration created a configurable global property");
+}
+
+let letGlobal = 1;
+const constGlobal = 2;
+if (globalThis.letGlobal !== undefined || "letGlobal" in globalThis || "constGlobal" in globalThis) {
+  throw new Error("'let' or 'const' created a global property");
+}
+if (letGlobal !== 1 || constGlobal !== 2) {
+  throw new Error("'let' or 'const' bindings were not readable");
+}
+
+function sloppyAssignment() {
+  sloppyGlobal = 2;
+}
+sloppyAssignment();
+if (globalThis.sloppyGlobal !== 2) {
+  throw new Error("Assigning an undeclared name in a function did not create a global property");
+}
+
+function strictAssignment() {
+  "use strict";
+  strictGlobal = 1;
+}
+let threw = false;
+try {
+  strictAssignment();
+} catch (err) {
+  threw = err instanceof ReferenceError;
+}
+if (!threw || "strictGlobal" in globalThis) {
+  throw new Error("Assigning an undeclared name in strict mode did not throw a ReferenceError");
+}
+
+// parseInt
+
+const parseIntCases = [
+  ["010", undefined, 10],
+  ["010", 10, 10],
+  ["0x10", undefined, 16],
+  ["0X10", undefined, 16],
+  ["0x10", 16, 16],
+  ["010", 8, 8],
+  ["0x10", 10, 0],
+  ["0x10", 8, 0],
+  ["  \n-010", undefined, -10],
+  ["\t+0x1f", undefined, 31],
+  ["-0X10", undefined, -16],
+  ["- 1", undefined, NaN],
+  ["0x", undefined, NaN],
+  ["0b11", undefined, 0],
+  ["-0", undefined, -0],
+  ["-0x0", 16, -0],
+  ["-" + "0".repeat(30), undefined, -0],
+  ["1" + "0".repeat(44), undefined, 1e44],
+  ["9".repeat(400), undefined, Infinity],
+  ["1" + "0".repeat(52) + "1", 2, 9007199254740992],
+];
+for (const parseIntCase of parseIntCases) {
+  const string = parseIntCase[0];
+  const radix = parseIntCase[1];
+  const expected = parseIntCase[2];
+  const result = parseInt(string, radix);
+  if (!Object.is(result, expected)) {
+    throw new Error(`'parseInt("${string}", ${radix})' returned ${result}, expected ${expected}`);
+  }
+}
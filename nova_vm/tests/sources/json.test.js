@@ -0,0 +1,82 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// JSON.stringify
+
+function assertStringify(expected, value, replacer, space) {
+  const result = JSON.stringify(value, replacer, space);
+  if (result !== expected) {
+    throw new Error(`'JSON.stringify' returned ${result}, expected ${expected}`);
+  }
+}
+
+assertStringify('{"d":1}', { a: undefined, b: function () {}, c: Symbol("c"), d: 1 });
+assertStringify("[null,null,null,2]", [undefined, function () {}, Symbol("y"), 2]);
+assertStringify("[null,1,null]", [, 1, undefined]);
+if (JSON.stringify(undefined) !== undefined || JSON.stringify(() => {}) !== undefined) {
+  throw new Error("'JSON.stringify' did not return undefined for an unserializable value");
+}
+
+const ordered = {};
+ordered.z = 1;
+ordered[10] = 2;
+ordered.a = 3;
+ordered[2] = 4;
+assertStringify('{"2":4,"10":2,"z":1,"a":3}', ordered);
+assertStringify(
+  '{"own":2}',
+  Object.create({ inherited: 1 }, { own: { value: 2, enumerable: true }, hidden: { value: 3 } }),
+);
+
+assertStringify('"\\u0001\\n\\t\\"\\\\\\u001f\\b\\f\\r"', "\u0001\n\t\"\\\u001f\b\f\r");
+assertStringify('{"k\\u0002\\"":"v"}', { "k\u0002\"": "v" });
+assertStringify('"é💩"', "é💩");
+
+assertStringify(
+  '[1,"s",false,0,1e+21,0.1,null,null,null,true]',
+  [new Number(1), new String("s"), new Boolean(false), -0, 1e21, 0.1, NaN, -Infinity, null, true],
+);
+assertStringify('{"k":"key:k"}', { k: { toJSON(key) { return "key:" + key; } } });
+assertStringify('"1970-01-01T00:00:00.000Z"', new Date(0));
+
+assertStringify('{"a":2,"b":[2,4],"c":{"d":6}}', { a: 1, b: [1, 2], c: { d: 3 } }, (key, value) =>
+  typeof value === "number" ? value * 2 : value,
+);
+assertStringify('{"b":2}', { a: 1, b: 2 }, (key, value) => (key === "a" ? undefined : value));
+assertStringify(
+  '{"c":3,"a":1,"1":4,"b":2}',
+  { a: 1, b: 2, c: 3, 1: 4 },
+  ["c", "a", 1, "a", new String("b")],
+);
+
+assertStringify('{\n--"a": [\n----1,\n----{\n------"b": 2\n----}\n--],\n--"e": [],\n--"f": {}\n}', {
+  a: [1, { b: 2 }],
+  e: [],
+  f: {},
+}, null, "--");
+assertStringify('{\n   "a": 1\n}', { a: 1 }, null, new Number(3));
+assertStringify('{\n          "a": 1\n}', { a: 1 }, null, 20);
+assertStringify('{\nabcdefghij"a": 1\n}', { a: 1 }, null, "abcdefghijklmnop");
+
+function assertStringifyThrows(value, message) {
+  let threw = false;
+  try {
+    JSON.stringify(value);
+  } catch (err) {
+    threw = err instanceof TypeError;
+  }
+  if (!threw) {
+    throw new Error(message);
+  }
+}
+
+const cyclic = {};
+cyclic.self = cyclic;
+assertStringifyThrows(cyclic, "'JSON.stringify' did not throw on a cyclic object");
+const cyclicArray = [1];
+cyclicArray.push([cyclicArray]);
+assertStringifyThrows(cyclicArray, "'JSON.stringify' did not throw on a cyclic array");
+assertStringifyThrows({ a: 1n }, "'JSON.stringify' did not throw on a BigInt");
+const shared = { x: 1 };
+assertStringify('[{"x":1},{"x":1}]', [shared, shared]);
@@ -0,0 +1,117 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// Map.prototype.clear and Map.prototype.delete
+
+const map = new Map([
+  [1, "a"],
+  [2, "b"],
+  [NaN, "c"],
+]);
+if (map.delete(1) !== true) {
+  throw new Error("'Map.prototype.delete' did not return true for a present key");
+}
+if (map.delete(1) !== false) {
+  throw new Error("'Map.prototype.delete' did not return false for a deleted key");
+}
+if (map.delete("missing") !== false) {
+  throw new Error("'Map.prototype.delete' did not return false for a missing key");
+}
+if (map.delete(NaN) !== true) {
+  throw new Error("'Map.prototype.delete' did not use SameValueZero for NaN");
+}
+if (map.size !== 1 || map.get(2) !== "b") {
+  throw new Error("'Map.prototype.delete' removed the wrong entries");
+}
+if (map.clear() !== undefined) {
+  throw new Error("'Map.prototype.clear' did not return undefined");
+}
+if (map.size !== 0 || map.has(2)) {
+  throw new Error("'Map.prototype.clear' did not empty the Map");
+}
+
+const iteratedMap = new Map([
+  [1, 1],
+  [2, 2],
+  [3, 3],
+]);
+const mapIterator = iteratedMap.keys();
+if (mapIterator.next().value !== 1) {
+  throw new Error("'Map.prototype.keys' did not start at the first key");
+}
+iteratedMap.delete(2);
+if (mapIterator.next().value !== 3) {
+  throw new Error("Map iterator did not skip a deleted entry");
+}
+iteratedMap.set(4, 4);
+iteratedMap.clear();
+if (mapIterator.next().done !== true) {
+  throw new Error("Map iterator was not done after 'clear'");
+}
+
+// Set.prototype.clear and Set.prototype.delete
+
+const set = new Set([1, 2, -0]);
+if (set.delete(1) !== true) {
+  throw new Error("'Set.prototype.delete' did not return true for a present value");
+}
+if (set.delete(1) !== false) {
+  throw new Error("'Set.prototype.delete' did not return false for a deleted value");
+}
+if (set.delete(0) !== true) {
+  throw new Error("'Set.prototype.delete' did not use SameValueZero for zero");
+}
+if (set.size !== 1 || !set.has(2)) {
+  throw new Error("'Set.prototype.delete' removed the wrong values");
+}
+if (set.clear() !== undefined) {
+  throw new Error("'Set.prototype.clear' did not return undefined");
+}
+if (set.size !== 0 || set.has(2)) {
+  throw new Error("'Set.prototype.clear' did not empty the Set");
+}
+
+const iteratedSet = new Set([1, 2, 3]);
+const setIterator = iteratedSet.values();
+setIterator.next();
+iteratedSet.clear();
+if (setIterator.next().done !== true) {
+  throw new Error("Set iterator was not done after 'clear'");
+}
+
+// Map.groupBy
+
+const groupedMap = Map.groupBy([1, 2, 3, 4], (value, index) => {
+  if (value !== index + 1) {
+    throw new Error("'Map.groupBy' passed the wrong index to the callback");
+  }
+  return value % 2 === 0 ? "even" : "odd";
+});
+if (!(groupedMap instanceof Map) || groupedMap.size !== 2) {
+  throw new Error("'Map.groupBy' did not return a Map with two groups");
+}
+if ([...groupedMap.keys()].join() !== "odd,even") {
+  throw new Error("'Map.groupBy' did not keep the groups in insertion order");
+}
+if (groupedMap.get("odd").join() !== "1,3" || groupedMap.get("even").join() !== "2,4") {
+  throw new Error("'Map.groupBy' groups had the wrong elements");
+}
+const keyObject = {};
+const groupedByObject = Map.groupBy([1, 2], () => keyObject);
+if (groupedByObject.get(keyObject).length !== 2) {
+  throw new Error("'Map.groupBy' did not group by object identity");
+}
+const groupedByZero = Map.groupBy([1, 2], (value) => (value === 1 ? -0 : 0));
+if (groupedByZero.size !== 1 || 1 / [...groupedByZero.keys()][0] !== Infinity) {
+  throw new Error("'Map.groupBy' did not canonicalize -0 keys");
+}
+let threw = false;
+try {
+  Map.groupBy([1], 5);
+} catch (err) {
+  threw = err instanceof TypeError;
+}
+if (!threw) {
+  throw new Error("'Map.groupBy' did not throw for a non-callable callback");
+}
@@ -0,0 +1,31 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// Math.round
+
+const roundCases = [
+  [0.5, 1],
+  [2.5, 3],
+  [-0.5, -0],
+  [-1.5, -1],
+  [-2.5, -2],
+  [-0.1, -0],
+  [0.1, 0],
+  [-0, -0],
+  [0.49999999999999994, 0],
+  // 2.4999999999999998 is exactly 2.5 as a Number, so use the Number below it.
+  [2.4999999999999996, 2],
+  [4503599627370495.5, 4503599627370496],
+  [Infinity, Infinity],
+  [-Infinity, -Infinity],
+  [NaN, NaN],
+];
+for (const roundCase of roundCases) {
+  const input = roundCase[0];
+  const expected = roundCase[1];
+  const result = Math.round(input);
+  if (!Object.is(result, expected)) {
+    throw new Error(`'Math.round(${input})' returned ${result}, expected ${expected}`);
+  }
+}
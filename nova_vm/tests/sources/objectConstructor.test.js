@@ -0,0 +1,78 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// Object.groupBy
+
+const groupedByProto = Object.groupBy([1, 2, 3], () => "__proto__");
+if (Object.getPrototypeOf(groupedByProto) !== null) {
+  throw new Error("'Object.groupBy' result did not have a null prototype");
+}
+if (!Object.hasOwn(groupedByProto, "__proto__")) {
+  throw new Error("'Object.groupBy' did not create an own '__proto__' group");
+}
+if (!Array.isArray(groupedByProto["__proto__"])) {
+  throw new Error("'Object.groupBy' group value was not an array");
+}
+if (groupedByProto["__proto__"].length !== 3) {
+  throw new Error("'Object.groupBy' '__proto__' group had the wrong length");
+}
+if ("toString" in groupedByProto) {
+  throw new Error("'Object.groupBy' result inherited 'toString'");
+}
+
+const groupedByParity = Object.groupBy([1, 2, 3, 4, 5], (value) => value % 2);
+if (Object.keys(groupedByParity).join() !== "0,1") {
+  throw new Error("'Object.groupBy' numeric keys were not converted to strings");
+}
+if (groupedByParity[1].join() !== "1,3,5" || groupedByParity[0].join() !== "2,4") {
+  throw new Error("'Object.groupBy' numeric key groups had wrong elements");
+}
+
+const symbolKey = Symbol("group");
+const groupedBySymbol = Object.groupBy(["a", "b"], () => symbolKey);
+if (Object.getOwnPropertySymbols(groupedBySymbol)[0] !== symbolKey) {
+  throw new Error("'Object.groupBy' did not support symbol keys");
+}
+if (groupedBySymbol[symbolKey].join() !== "a,b") {
+  throw new Error("'Object.groupBy' symbol key group had wrong elements");
+}
+
+const assignTarget = {};
+if (Object.assign(assignTarget, { a: 1 }) !== assignTarget) {
+  throw new Error("'Object.assign' did not return the target");
+}
+if (assignTarget.a !== 1) {
+  throw new Error("'Object.assign' did not copy a property to the target");
+}
+for (const invalidTarget of [null, undefined]) {
+  let threwTypeError = false;
+  try {
+    Object.assign(invalidTarget, {});
+  } catch (err) {
+    threwTypeError = err instanceof TypeError;
+  }
+  if (!threwTypeError) {
+    throw new Error("'Object.assign' did not throw a TypeError for a nullish target");
+  }
+}
+const assignedFromNullish = Object.assign({}, null, undefined, { b: 2 });
+if (Object.keys(assignedFromNullish).join() !== "b" || assignedFromNullish.b !== 2) {
+  throw new Error("'Object.assign' did not skip nullish sources");
+}
+const assignedFromString = Object.assign({}, "abc");
+if (
+  Object.keys(assignedFromString).join() !== "0,1,2" ||
+  assignedFromString[0] !== "a" ||
+  assignedFromString[1] !== "b" ||
+  assignedFromString[2] !== "c"
+) {
+  throw new Error("'Object.assign' did not copy the characters of a string source");
+}
+const wrappedTarget = Object.assign(1, { x: 3 });
+if (typeof wrappedTarget !== "object" || !(wrappedTarget instanceof Number)) {
+  throw new Error("'Object.assign' did not wrap a primitive target");
+}
+if (wrappedTarget.x !== 3 || wrappedTarget.valueOf() !== 1) {
+  throw new Error("'Object.assign' did not copy properties to a wrapped target");
+}
@@ -0,0 +1,59 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// This file is evaluated first and its promise jobs are run to completion;
+// after that `checkAfterJobs` is called to verify the observed results.
+
+// Promise.prototype.then
+
+const log = [];
+
+Promise.resolve(1).then((value) => {
+  log.push(`async ${value}`);
+});
+log.push("sync");
+
+Promise.resolve(1)
+  .then((value) => Promise.resolve(value + 1))
+  .then((value) => log.push(`chained ${value}`));
+
+Promise.resolve(1)
+  .then(() => ({ then: (resolve) => resolve(42) }))
+  .then((value) => log.push(`thenable ${value}`));
+
+Promise.resolve(7)
+  .then(undefined)
+  .then((value) => log.push(`fulfilled passthrough ${value}`));
+
+Promise.reject(new Error("rejected"))
+  .then(() => log.push("unexpected fulfillment"))
+  .then(undefined, (err) => log.push(`rejected passthrough ${err.message}`));
+
+const thrownError = new TypeError("thrown");
+const thrown = Promise.resolve(1).then(() => {
+  throw thrownError;
+});
+thrown.then(undefined, (err) => log.push(`thrown ${err === thrownError}`));
+
+if (!(thrown instanceof Promise)) {
+  throw new Error("'then' did not return a new Promise");
+}
+if (log.join() !== "sync") {
+  throw new Error("'then' handler ran synchronously");
+}
+
+function checkAfterJobs() {
+  const expected = [
+    "sync",
+    "async 1",
+    "fulfilled passthrough 7",
+    "rejected passthrough rejected",
+    "thrown true",
+    "thenable 42",
+    "chained 2",
+  ];
+  if (log.join() !== expected.join()) {
+    throw new Error(`Unexpected 'then' job order: ${log.join()}`);
+  }
+}
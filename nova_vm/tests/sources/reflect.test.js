@@ -0,0 +1,60 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertThrows(errorType, fn, message) {
+  try {
+    fn();
+  } catch (err) {
+    if (err instanceof errorType) {
+      return;
+    }
+    throw new Error(`${message}: threw the wrong error type`);
+  }
+  throw new Error(`${message}: did not throw`);
+}
+
+// Reflect.setPrototypeOf
+
+const parent = {};
+const child = Object.create(parent);
+if (Reflect.setPrototypeOf(parent, child) !== false) {
+  throw new Error("'Reflect.setPrototypeOf' allowed a prototype cycle");
+}
+if (Object.getPrototypeOf(parent) !== Object.prototype) {
+  throw new Error("'Reflect.setPrototypeOf' changed the prototype on a cycle");
+}
+if (Reflect.setPrototypeOf(parent, parent) !== false) {
+  throw new Error("'Reflect.setPrototypeOf' allowed a self-cycle");
+}
+
+const nonExtensible = Object.preventExtensions({});
+if (Reflect.setPrototypeOf(nonExtensible, null) !== false) {
+  throw new Error(
+    "'Reflect.setPrototypeOf' changed the prototype of a non-extensible object",
+  );
+}
+if (Object.getPrototypeOf(nonExtensible) !== Object.prototype) {
+  throw new Error("'Reflect.setPrototypeOf' mutated a non-extensible object");
+}
+if (Reflect.setPrototypeOf(nonExtensible, Object.prototype) !== true) {
+  throw new Error(
+    "'Reflect.setPrototypeOf' rejected setting the same prototype on a non-extensible object",
+  );
+}
+
+const target = {};
+if (Reflect.setPrototypeOf(target, null) !== true) {
+  throw new Error("'Reflect.setPrototypeOf' rejected a null prototype");
+}
+if (Object.getPrototypeOf(target) !== null) {
+  throw new Error("'Reflect.setPrototypeOf' did not set a null prototype");
+}
+
+assertThrows(TypeError, () => Reflect.setPrototypeOf(1, null), "'Reflect.setPrototypeOf(1, null)'");
+assertThrows(TypeError, () => Reflect.setPrototypeOf({}, 1), "'Reflect.setPrototypeOf({}, 1)'");
+assertThrows(
+  TypeError,
+  () => Reflect.setPrototypeOf({}, undefined),
+  "'Reflect.setPrototypeOf({}, undefined)'",
+);
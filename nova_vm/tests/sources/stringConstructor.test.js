@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// String ( value )
+
+if (String(123) !== "123") {
+  throw new Error("'String(123)' did not produce \"123\"");
+}
+if (String(null) !== "null") {
+  throw new Error("'String(null)' did not produce \"null\"");
+}
+if (String(undefined) !== "undefined") {
+  throw new Error("'String(undefined)' did not produce \"undefined\"");
+}
+if (String(Symbol("x")) !== "Symbol(x)") {
+  throw new Error("'String(Symbol(\"x\"))' did not produce \"Symbol(x)\"");
+}
+if (String() !== "") {
+  throw new Error("'String()' did not produce the empty string");
+}
+const stringHinted = {
+  toString() {
+    return "toString";
+  },
+  valueOf() {
+    return "valueOf";
+  },
+};
+if (String(stringHinted) !== "toString") {
+  throw new Error("'String(object)' did not use the string hint");
+}
+
+let threw = false;
+try {
+  new String(Symbol("x"));
+} catch (err) {
+  threw = err instanceof TypeError;
+}
+if (!threw) {
+  throw new Error("'new String(Symbol())' did not throw a TypeError");
+}
+
+// String exotic objects
+
+const wrapper = new String("ab");
+if (typeof wrapper !== "object") {
+  throw new Error("'new String(\"ab\")' did not produce an object");
+}
+if (wrapper.length !== 2) {
+  throw new Error("'new String(\"ab\").length' was not 2");
+}
+if (wrapper[0] !== "a" || wrapper[1] !== "b" || wrapper[2] !== undefined) {
+  throw new Error("'new String(\"ab\")' indexed access was incorrect");
+}
+if (!wrapper.hasOwnProperty(0) || !wrapper.hasOwnProperty("1")) {
+  throw new Error("'new String(\"ab\")' did not have own index properties");
+}
+if (Object.getOwnPropertyNames(wrapper).join() !== "0,1,length") {
+  throw new Error("'new String(\"ab\")' own property keys were incorrect");
+}
+const lengthDescriptor = Object.getOwnPropertyDescriptor(wrapper, "length");
+if (
+  lengthDescriptor.writable ||
+  lengthDescriptor.enumerable ||
+  lengthDescriptor.configurable
+) {
+  throw new Error("'new String(\"ab\").length' was not read-only");
+}
+wrapper.length = 5;
+if (wrapper.length !== 2) {
+  throw new Error("'new String(\"ab\").length' was overwritten");
+}
+const indexDescriptor = Object.getOwnPropertyDescriptor(wrapper, "0");
+if (
+  indexDescriptor.writable ||
+  !indexDescriptor.enumerable ||
+  indexDescriptor.configurable
+) {
+  throw new Error("'new String(\"ab\")[0]' had the wrong attributes");
+}
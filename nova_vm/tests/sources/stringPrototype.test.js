@@ -0,0 +1,112 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// String.prototype.normalize
+
+const decomposed = "é";
+const composed = "é";
+if (decomposed.normalize() !== composed) {
+  throw new Error("'normalize()' did not default to NFC");
+}
+if (decomposed.normalize("NFC") !== composed) {
+  throw new Error("'normalize(\"NFC\")' did not compose");
+}
+if (composed.normalize("NFD") !== decomposed) {
+  throw new Error("'normalize(\"NFD\")' did not decompose");
+}
+const ligature = "ﬁ";
+if (ligature.normalize("NFKC") !== "fi") {
+  throw new Error("'normalize(\"NFKC\")' did not apply compatibility mapping");
+}
+if ("ẛ̣".normalize("NFKD") !== "ṩ") {
+  throw new Error("'normalize(\"NFKD\")' did not fully decompose");
+}
+if (ligature.normalize("NFC") !== ligature) {
+  throw new Error("'normalize(\"NFC\")' applied a compatibility mapping");
+}
+let threw = false;
+try {
+  "abc".normalize("nfc");
+} catch (err) {
+  threw = err instanceof RangeError;
+}
+if (!threw) {
+  throw new Error("'normalize' did not throw RangeError on an invalid form");
+}
+// Lone surrogates pass through unchanged while the characters around them are
+// normalized. Note: Nova strings cannot hold a lone surrogate yet, so
+// fromCharCode currently stores U+FFFD in its place; the check is against
+// whatever code unit the string holds.
+const surrogate = String.fromCharCode(0xd800);
+const withSurrogate = decomposed + surrogate + composed;
+for (const forms of [
+  ["NFC", composed],
+  ["NFD", decomposed],
+  ["NFKC", composed],
+  ["NFKD", decomposed],
+]) {
+  const form = forms[0];
+  const expected = forms[1];
+  if (withSurrogate.normalize(form) !== expected + surrogate + expected) {
+    throw new Error(`'normalize("${form}")' did not pass the surrogate through`);
+  }
+}
+
+// Indexed access on small and heap strings
+
+// "a💩b" fits in a small string, the padded version does not.
+const small = "a💩b";
+const heap = "padding!" + small;
+const offset = heap.length - small.length;
+if (small.length !== 4 || heap.length !== 12) {
+  throw new Error("'length' did not count UTF-16 code units");
+}
+const expectedCodeUnits = [0x61, 0xd83d, 0xdca9, 0x62];
+const expectedCodePoints = [0x61, 0x1f4a9, 0xdca9, 0x62];
+for (let i = 0; i < small.length; i++) {
+  if (small.charCodeAt(i) !== expectedCodeUnits[i]) {
+    throw new Error(`'charCodeAt(${i})' on a small string was wrong`);
+  }
+  if (heap.charCodeAt(offset + i) !== expectedCodeUnits[i]) {
+    throw new Error(`'charCodeAt(${offset + i})' on a heap string was wrong`);
+  }
+  if (small.codePointAt(i) !== expectedCodePoints[i]) {
+    throw new Error(`'codePointAt(${i})' on a small string was wrong`);
+  }
+  if (heap.codePointAt(offset + i) !== expectedCodePoints[i]) {
+    throw new Error(`'codePointAt(${offset + i})' on a heap string was wrong`);
+  }
+  if (small.charAt(i) !== heap.charAt(offset + i)) {
+    throw new Error(`'charAt(${i})' differed between small and heap strings`);
+  }
+  if (small.at(i) !== heap.at(offset + i) || small.at(i - 4) !== heap.at(i - 4)) {
+    throw new Error(`'at(${i})' differed between small and heap strings`);
+  }
+  if (small[i] !== heap[offset + i]) {
+    throw new Error(`'[${i}]' differed between small and heap strings`);
+  }
+}
+if (small.charAt(0) !== "a" || small.at(-1) !== "b" || heap.at(0) !== "p") {
+  throw new Error("'charAt' or 'at' returned the wrong character");
+}
+if (small.charAt(1).length !== 1 || heap.at(offset + 2).length !== 1) {
+  throw new Error("'charAt' or 'at' did not return a single code unit");
+}
+if (
+  !Number.isNaN(small.charCodeAt(4)) ||
+  small.codePointAt(4) !== undefined ||
+  small.charAt(4) !== "" ||
+  small.at(4) !== undefined
+) {
+  throw new Error("Out of bounds access did not return the right sentinel");
+}
+
+// String.prototype.repeat on short strings
+
+if ("ab".repeat(3) !== "ababab" || "ab".repeat(4) !== "abababab") {
+  throw new Error("'repeat' did not repeat a short string");
+}
+if ("x".repeat(0) !== "" || "é".repeat(3) !== "ééé" || "💩".repeat(2).length !== 4) {
+  throw new Error("'repeat' gave the wrong result for a short string");
+}
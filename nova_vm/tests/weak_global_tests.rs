@@ -0,0 +1,107 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{
+        execution::{
+            agent::{GcAgent, Options},
+            Agent, DefaultHostHooks,
+        },
+        scripts_and_modules::script::{parse_script, script_evaluation},
+        types::{String, Value},
+    },
+    engine::{context::GcScope, Global, WeakGlobal},
+};
+
+fn evaluate(agent: &mut Agent, source: &str, mut gc: GcScope<'_, '_>) -> Value {
+    let realm = agent.current_realm_id();
+    let source_text = String::from_string(agent, source.to_string(), gc.nogc());
+    let script = parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+    script_evaluation(agent, script, gc.reborrow()).unwrap()
+}
+
+#[test]
+fn weak_global_is_some_while_strongly_held() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let (strong, weak) = agent.run_in_realm(&realm, |agent, gc| {
+        let object = evaluate(agent, "({ a: 1 })", gc);
+        (Global::new(agent, object), WeakGlobal::new(agent, object))
+    });
+    agent.gc();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let object = strong.get(agent, gc.reborrow());
+        assert_eq!(weak.get(agent, gc), Some(object));
+    });
+}
+
+#[test]
+fn weak_global_is_none_after_strong_reference_is_dropped() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let (strong, weak) = agent.run_in_realm(&realm, |agent, gc| {
+        let object = evaluate(agent, "({ a: 1 })", gc);
+        (Global::new(agent, object), WeakGlobal::new(agent, object))
+    });
+    agent.run_in_realm(&realm, |agent, _| {
+        strong.take(agent);
+    });
+    agent.gc();
+    agent.run_in_realm(&realm, |agent, gc| {
+        assert_eq!(weak.get(agent, gc), None);
+    });
+}
+
+#[test]
+fn weak_global_does_not_prevent_collection() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let (weak, other) = agent.run_in_realm(&realm, |agent, mut gc| {
+        let object = evaluate(agent, "({ a: 1 })", gc.reborrow());
+        let other = evaluate(agent, "({ b: 2 })", gc);
+        (WeakGlobal::new(agent, object), Global::new(agent, other))
+    });
+    agent.gc();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        // The surviving object is compacted into the collected object's
+        // place; the cleared WeakGlobal must not point to it.
+        assert_eq!(weak.get(agent, gc.reborrow()), None);
+        assert!(other.get(agent, gc).is_object());
+    });
+}
+
+#[test]
+fn weak_global_to_stack_value_is_never_cleared() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let weak = agent.run_in_realm(&realm, |agent, _| WeakGlobal::new(agent, Value::from(42)));
+    agent.gc();
+    agent.run_in_realm(&realm, |agent, gc| {
+        assert_eq!(weak.get(agent, gc), Some(Value::from(42)));
+    });
+}
+
+#[test]
+fn cleared_weak_global_is_not_reused_by_new_weak_global() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let weak = agent.run_in_realm(&realm, |agent, gc| {
+        let object = evaluate(agent, "({ a: 1 })", gc);
+        WeakGlobal::new(agent, object)
+    });
+    agent.gc();
+    let (strong, new_weak) = agent.run_in_realm(&realm, |agent, gc| {
+        let object = evaluate(agent, "({ b: 2 })", gc);
+        (Global::new(agent, object), WeakGlobal::new(agent, object))
+    });
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let object = strong.get(agent, gc.reborrow());
+        // The collected value's slot must not be handed to the new
+        // WeakGlobal while the old WeakGlobal still refers to it.
+        assert_eq!(weak.get(agent, gc.reborrow()), None);
+        assert_eq!(new_weak.get(agent, gc.reborrow()), Some(object));
+        assert_eq!(weak.take(agent), None);
+        assert_eq!(new_weak.take(agent), Some(object));
+    });
+}
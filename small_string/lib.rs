@@ -3,7 +3,12 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 #![no_std]
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg(test)]
+extern crate std;
+
+use wtf8::Wtf8;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SmallString {
     /// The string will be padded to 7 bytes with the 0xFF byte, which is never
     /// contained in valid UTF-8 or WTF-8.
@@ -16,11 +21,45 @@ impl core::fmt::Debug for SmallString {
     }
 }
 
+impl core::fmt::Display for SmallString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// Hash as a Wtf8 so that `Borrow<Wtf8>` can be used for map lookups.
+impl core::hash::Hash for SmallString {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_wtf8().hash(state)
+    }
+}
+
+impl AsRef<Wtf8> for SmallString {
+    fn as_ref(&self) -> &Wtf8 {
+        self.as_wtf8()
+    }
+}
+
+impl AsRef<[u8]> for SmallString {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl core::borrow::Borrow<Wtf8> for SmallString {
+    fn borrow(&self) -> &Wtf8 {
+        self.as_wtf8()
+    }
+}
+
 impl SmallString {
     pub const EMPTY: SmallString = Self {
         bytes: [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
     };
 
+    /// The maximum length of a small string in bytes.
+    pub const MAX_LEN: usize = 7;
+
     pub fn len(&self) -> usize {
         // Find the first 0xFF byte. Small strings must be valid UTF-8, and
         // UTF-8 can never contain 0xFF, so that must mark the end of the
@@ -32,6 +71,17 @@ impl SmallString {
         self.as_str().chars().map(char::len_utf16).sum()
     }
 
+    /// Returns the number of code points in the string. This is distinct from
+    /// both the byte length and the UTF-16 length.
+    pub fn char_count(&self) -> usize {
+        self.as_str().chars().count()
+    }
+
+    /// Iterate over the code points of the string.
+    pub fn code_points(&self) -> impl Iterator<Item = char> + '_ {
+        self.as_str().chars()
+    }
+
     // TODO: This should return a wtf8::CodePoint.
     pub fn utf16_char(&self, idx: usize) -> char {
         let mut u16_i = 0;
@@ -46,6 +96,37 @@ impl SmallString {
         panic!("Index out of bounds");
     }
 
+    /// Returns the UTF-16 code unit at the given UTF-16 index.
+    ///
+    /// ## Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn char_code_at(&self, idx: usize) -> u16 {
+        // The second code unit of a surrogate pair has no UTF-8 index of its
+        // own; it is found through the first one.
+        let (utf8_idx, unit) = match self.utf8_index(idx) {
+            Some(utf8_idx) => (utf8_idx, 0),
+            None => (self.utf8_index(idx - 1).unwrap(), 1),
+        };
+        let ch = self.as_str()[utf8_idx..].chars().next().unwrap();
+        let mut buf = [0; 2];
+        ch.encode_utf16(&mut buf)[unit]
+    }
+
+    /// Returns the code point starting at the given UTF-16 index. If the index
+    /// is the second code unit of a surrogate pair, the code unit itself is
+    /// returned as in the CodePointAt abstract operation.
+    ///
+    /// ## Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn code_point_at(&self, idx: usize) -> u32 {
+        match self.utf8_index(idx) {
+            Some(utf8_idx) => self.as_str()[utf8_idx..].chars().next().unwrap() as u32,
+            None => self.char_code_at(idx) as u32,
+        }
+    }
+
     pub fn utf8_index(&self, utf16_idx: usize) -> Option<usize> {
         let mut current_utf16_index = 0;
         for (idx, ch) in self.as_str().char_indices() {
@@ -57,8 +138,12 @@ impl SmallString {
                 }
             }
         }
-        assert_eq!(utf16_idx, current_utf16_index);
-        Some(self.len())
+        match current_utf16_index.cmp(&utf16_idx) {
+            core::cmp::Ordering::Equal => Some(self.len()),
+            // The index is the second code unit of the last character.
+            core::cmp::Ordering::Greater => None,
+            core::cmp::Ordering::Less => panic!("Index out of bounds"),
+        }
     }
 
     pub fn utf16_index(&self, utf8_idx: usize) -> usize {
@@ -75,12 +160,51 @@ impl SmallString {
         utf16_idx
     }
 
+    /// Returns the substring between the given UTF-16 indices.
+    ///
+    /// Returns `None` if the range is out of bounds or reversed, or if either
+    /// end would split a surrogate pair, as a small string cannot contain lone
+    /// surrogates.
+    pub fn slice_utf16(&self, start: usize, end: usize) -> Option<SmallString> {
+        if start > end || end > self.utf16_len() {
+            return None;
+        }
+        let start = self.utf8_index(start)?;
+        let end = self.utf8_index(end)?;
+        Some(Self::from_str_unchecked(&self.as_str()[start..end]))
+    }
+
+    /// Returns the substring in the given byte range, or `None` if the range
+    /// is out of bounds or does not lie on character boundaries.
+    pub fn byte_slice(&self, range: core::ops::Range<usize>) -> Option<&str> {
+        self.as_str().get(range)
+    }
+
+    /// Splits the string into two at the given byte index, or returns `None`
+    /// if the index is out of bounds or not on a character boundary.
+    pub fn split_at(&self, mid: usize) -> Option<(SmallString, SmallString)> {
+        let string = self.as_str();
+        if !string.is_char_boundary(mid) {
+            return None;
+        }
+        let (first, second) = string.split_at(mid);
+        Some((
+            Self::from_str_unchecked(first),
+            Self::from_str_unchecked(second),
+        ))
+    }
+
     #[inline]
     pub fn as_str(&self) -> &str {
         // SAFETY: Guaranteed to be UTF-8.
         unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
     }
 
+    #[inline]
+    pub fn as_wtf8(&self) -> &Wtf8 {
+        Wtf8::from_str(self.as_str())
+    }
+
     #[inline]
     pub fn as_bytes(&self) -> &[u8] {
         self.bytes.as_slice().split_at(self.len()).0
@@ -185,6 +309,88 @@ impl SmallString {
         ch.encode_utf8(&mut bytes);
         SmallString { bytes }
     }
+
+    /// Checks that two small strings are an ASCII case-insensitive match.
+    ///
+    /// Non-ASCII characters must match exactly.
+    pub fn eq_ignore_ascii_case(&self, other: &SmallString) -> bool {
+        // The 0xFF padding is unaffected by ASCII case folding, so comparing
+        // all bytes also compares the lengths.
+        self.bytes.eq_ignore_ascii_case(&other.bytes)
+    }
+
+    /// Returns a copy of the string with ASCII letters mapped to lowercase.
+    ///
+    /// Non-ASCII characters are left unchanged: this is not full Unicode case
+    /// folding.
+    pub fn to_ascii_lowercase(&self) -> SmallString {
+        let mut bytes = self.bytes;
+        bytes.make_ascii_lowercase();
+        SmallString { bytes }
+    }
+
+    /// Returns a copy of the string with ASCII letters mapped to uppercase.
+    ///
+    /// Non-ASCII characters are left unchanged: this is not full Unicode case
+    /// folding.
+    pub fn to_ascii_uppercase(&self) -> SmallString {
+        let mut bytes = self.bytes;
+        bytes.make_ascii_uppercase();
+        SmallString { bytes }
+    }
+
+    /// Concatenate two small strings.
+    ///
+    /// Returns `None` if the result would not fit in [`Self::MAX_LEN`] bytes,
+    /// in which case the caller must allocate a heap string instead.
+    pub fn try_concat(&self, other: &SmallString) -> Option<SmallString> {
+        let len = self.len();
+        let other_len = other.len();
+        if len + other_len > Self::MAX_LEN {
+            return None;
+        }
+        // Concatenating two valid UTF-8 strings gives a valid UTF-8 string,
+        // and the bytes after the new end are still 0xFF padding.
+        let mut bytes = self.bytes;
+        bytes[len..len + other_len].copy_from_slice(other.as_bytes());
+        Some(SmallString { bytes })
+    }
+
+    /// Repeat the small string `count` times.
+    ///
+    /// Returns `None` if the result would not fit in [`Self::MAX_LEN`] bytes,
+    /// in which case the caller must allocate a heap string instead.
+    pub fn try_repeat(&self, count: usize) -> Option<SmallString> {
+        let len = self.len();
+        if count == 0 || len == 0 {
+            return Some(Self::EMPTY);
+        }
+        // Checking the count first keeps the multiplication from overflowing.
+        if count > Self::MAX_LEN || len * count > Self::MAX_LEN {
+            return None;
+        }
+        let mut bytes = Self::EMPTY.bytes;
+        for chunk in bytes[..len * count].chunks_exact_mut(len) {
+            chunk.copy_from_slice(self.as_bytes());
+        }
+        Some(SmallString { bytes })
+    }
+
+    /// Append a character to the small string.
+    ///
+    /// Returns `None` if the result would not fit in [`Self::MAX_LEN`] bytes.
+    pub fn try_push_char(&self, ch: char) -> Option<SmallString> {
+        self.try_concat(&SmallString::from_code_point(ch))
+    }
+
+    /// Append a code point to the small string.
+    ///
+    /// Returns `None` if the result would not fit in [`Self::MAX_LEN`] bytes,
+    /// or if the code point is not a Unicode scalar value: small strings must
+    /// be valid UTF-8 and thus cannot contain lone surrogates.
+    pub fn try_push_code_point(&self, code_point: u32) -> Option<SmallString> {
+        self.try_push_char(char::from_u32(code_point)?)
+    }
 }
 
 impl TryFrom<&str> for SmallString {
@@ -230,3 +436,177 @@ fn valid_stack_strings() {
 fn not_valid_stack_strings() {
     assert!(SmallString::try_from("asd asd r 547 gdfg").is_err());
 }
+
+#[test]
+fn concat_small_strings() {
+    let abc = SmallString::try_from("abc").unwrap();
+    let de = SmallString::try_from("de").unwrap();
+    let abcde = abc.try_concat(&de).unwrap();
+    assert_eq!(abcde.as_str(), "abcde");
+    assert_eq!(abcde.data(), b"abcde\xFF\xFF");
+    assert_eq!(abcde.try_concat(&SmallString::EMPTY), Some(abcde));
+    assert_eq!(SmallString::EMPTY.try_concat(&abcde), Some(abcde));
+}
+
+#[test]
+fn concat_small_strings_overflow() {
+    let abcd = SmallString::try_from("abcd").unwrap();
+    assert_eq!(
+        abcd.try_concat(&SmallString::try_from("efg").unwrap())
+            .unwrap()
+            .as_str(),
+        "abcdefg"
+    );
+    assert_eq!(
+        abcd.try_concat(&SmallString::try_from("efgh").unwrap()),
+        None
+    );
+    assert_eq!(
+        SmallString::try_from("abcdefg").unwrap().try_push_char('h'),
+        None
+    );
+}
+
+#[test]
+fn push_code_points() {
+    let abc = SmallString::try_from("abc").unwrap();
+    let with_emoji = abc.try_push_char('💩').unwrap();
+    assert_eq!(with_emoji.as_str(), "abc💩");
+    assert_eq!(with_emoji.len(), 7);
+    assert_eq!(with_emoji.try_push_char('a'), None);
+    assert_eq!(
+        SmallString::try_from("abcd").unwrap().try_push_char('💩'),
+        None
+    );
+    assert_eq!(abc.try_push_code_point(0x1F4A9), Some(with_emoji));
+    assert_eq!(abc.try_push_code_point(0xD800), None);
+}
+
+#[test]
+fn code_points_of_small_strings() {
+    let ascii = SmallString::try_from("abc").unwrap();
+    assert_eq!(ascii.char_count(), 3);
+    assert!(ascii.code_points().eq(['a', 'b', 'c']));
+
+    let multi_byte = SmallString::try_from("a💩é").unwrap();
+    assert_eq!(multi_byte.len(), 7);
+    assert_eq!(multi_byte.utf16_len(), 4);
+    assert_eq!(multi_byte.char_count(), 3);
+    assert!(multi_byte.code_points().eq(['a', '💩', 'é']));
+
+    let single = SmallString::from_code_point('💩');
+    assert_eq!(single.char_count(), 1);
+    assert!(single.code_points().eq(['💩']));
+
+    assert_eq!(SmallString::EMPTY.char_count(), 0);
+    assert_eq!(SmallString::EMPTY.code_points().next(), None);
+}
+
+#[test]
+fn ascii_case_folding() {
+    let mixed = SmallString::try_from("aB💩").unwrap();
+    let lower = mixed.to_ascii_lowercase();
+    let upper = mixed.to_ascii_uppercase();
+    assert_eq!(lower.as_str(), "ab💩");
+    assert_eq!(upper.as_str(), "AB💩");
+    assert_eq!(lower.len(), mixed.len());
+    assert_eq!(upper.data(), b"AB\xF0\x9F\x92\xA9\xFF");
+    assert!(lower.eq_ignore_ascii_case(&upper));
+    assert!(mixed.eq_ignore_ascii_case(&SmallString::try_from("Ab💩").unwrap()));
+    assert!(!mixed.eq_ignore_ascii_case(&SmallString::try_from("aB").unwrap()));
+    assert!(!SmallString::try_from("é")
+        .unwrap()
+        .eq_ignore_ascii_case(&SmallString::try_from("É").unwrap()));
+}
+
+#[test]
+fn code_units_and_code_points() {
+    let string = SmallString::try_from("a💩b").unwrap();
+    assert_eq!(string.utf16_len(), 4);
+    assert_eq!(string.char_code_at(0), 'a' as u16);
+    assert_eq!(string.char_code_at(1), 0xD83D);
+    assert_eq!(string.char_code_at(2), 0xDCA9);
+    assert_eq!(string.char_code_at(3), 'b' as u16);
+    assert_eq!(string.code_point_at(0), 'a' as u32);
+    assert_eq!(string.code_point_at(1), 0x1F4A9);
+    assert_eq!(string.code_point_at(2), 0xDCA9);
+    assert_eq!(string.code_point_at(3), 'b' as u32);
+
+    let string = SmallString::from_code_point('💩');
+    assert_eq!(string.utf8_index(1), None);
+    assert_eq!(string.char_code_at(1), 0xDCA9);
+    assert_eq!(string.code_point_at(1), 0xDCA9);
+}
+
+#[test]
+fn slice_small_strings() {
+    let string = SmallString::try_from("aé💩").unwrap();
+    assert_eq!(string.utf16_len(), 4);
+    assert_eq!(string.slice_utf16(0, 2).unwrap().as_str(), "aé");
+    assert_eq!(string.slice_utf16(1, 4).unwrap().as_str(), "é💩");
+    assert_eq!(string.slice_utf16(2, 2), Some(SmallString::EMPTY));
+    // Slicing across a surrogate half.
+    assert_eq!(string.slice_utf16(0, 3), None);
+    assert_eq!(string.slice_utf16(3, 4), None);
+    assert_eq!(string.slice_utf16(2, 5), None);
+    assert_eq!(string.slice_utf16(2, 1), None);
+
+    // Slicing across a multi-byte character.
+    assert_eq!(string.byte_slice(0..3), Some("aé"));
+    assert_eq!(string.byte_slice(0..2), None);
+    assert_eq!(string.byte_slice(3..7), Some("💩"));
+    assert_eq!(string.byte_slice(3..8), None);
+
+    let (first, second) = string.split_at(3).unwrap();
+    assert_eq!((first.as_str(), second.as_str()), ("aé", "💩"));
+    assert_eq!(string.split_at(4), None);
+    assert_eq!(string.split_at(8), None);
+}
+
+#[test]
+fn display_small_strings() {
+    let string = SmallString::try_from("a\"💩").unwrap();
+    assert_eq!(std::format!("{}", string), "a\"💩");
+    assert_eq!(std::format!("{:?}", string), "\"a\"💩\"");
+    assert_eq!(std::format!("{}", SmallString::EMPTY), "");
+}
+
+#[test]
+fn small_string_as_map_key() {
+    let mut map = std::collections::HashMap::new();
+    map.insert(SmallString::try_from("foo").unwrap(), 1);
+    map.insert(SmallString::from('💩'), 2);
+    assert_eq!(map.get(Wtf8::from_str("foo")), Some(&1));
+    assert_eq!(map.get(Wtf8::from_str("💩")), Some(&2));
+    assert_eq!(map.get(Wtf8::from_str("fo")), None);
+    let string = SmallString::try_from("foo").unwrap();
+    assert_eq!(AsRef::<Wtf8>::as_ref(&string), Wtf8::from_str("foo"));
+    assert_eq!(AsRef::<[u8]>::as_ref(&string), b"foo");
+}
+
+#[test]
+fn repeat_small_strings() {
+    let string = SmallString::try_from("ab").unwrap();
+    assert_eq!(string.try_repeat(3).unwrap().as_str(), "ababab");
+    assert_eq!(string.try_repeat(4), None);
+    assert_eq!(string.try_repeat(1), Some(string));
+    assert_eq!(string.try_repeat(usize::MAX), None);
+    assert_eq!(
+        SmallString::from('x').try_repeat(0),
+        Some(SmallString::EMPTY)
+    );
+    assert_eq!(
+        SmallString::from('x').try_repeat(7).unwrap().as_str(),
+        "xxxxxxx"
+    );
+    assert_eq!(SmallString::from('x').try_repeat(8), None);
+    assert_eq!(
+        SmallString::EMPTY.try_repeat(usize::MAX),
+        Some(SmallString::EMPTY)
+    );
+    assert_eq!(
+        SmallString::from('é').try_repeat(3).unwrap().as_str(),
+        "ééé"
+    );
+    assert_eq!(SmallString::from('💩').try_repeat(2), None);
+}